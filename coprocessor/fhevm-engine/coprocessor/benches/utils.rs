@@ -94,6 +94,7 @@ async fn start_coprocessor(rx: Receiver<bool>, app_port: u16, db_url: &str) {
         tenant_key_cache_size: 4,
         coprocessor_fhe_threads: 128,
         maximum_handles_per_input: 255,
+        disabled_operations: Vec::new(),
         tokio_threads: 16,
         pg_pool_max_connections: 2,
         server_addr: format!("127.0.0.1:{app_port}"),
@@ -1,4 +1,5 @@
 use clap::Parser;
+use fhevm_engine_common::types::SupportedFheOperations;
 use tracing::Level;
 
 #[derive(Parser, Debug, Clone)]
@@ -44,6 +45,13 @@ pub struct Args {
     #[arg(long, default_value_t = 255)]
     pub maximum_handles_per_input: u8,
 
+    /// FHE operations to refuse, by name, e.g. FheDiv,FheRem.
+    /// The API rejects them on submission and the background worker marks
+    /// computations queued directly in the database, e.g. by the listener,
+    /// as failed instead of executing them
+    #[arg(long, value_delimiter = ',')]
+    pub disabled_operations: Vec<SupportedFheOperations>,
+
     /// Coprocessor FHE processing threads
     #[arg(long, default_value_t = 8)]
     pub coprocessor_fhe_threads: usize,
@@ -8,9 +8,6 @@ use opentelemetry::trace::Span;
 use opentelemetry::KeyValue;
 use sqlx::{query, Postgres};
 
-#[cfg(feature = "gpu")]
-use tfhe::core_crypto::gpu::get_number_of_gpus;
-
 /// Returns tenant id upon valid authorization request
 pub async fn check_if_api_key_is_valid<T>(
     req: &tonic::Request<T>,
@@ -143,7 +140,6 @@ where
                 .expect("We can't deserialize our own validated pks key");
             let public_params: tfhe::zk::CompactPkeCrs = safe_deserialize_key(&key.public_params)
                 .expect("We can't deserialize our own validated public params");
-            let num_gpus = get_number_of_gpus() as u64;
             res.push(TfheTenantKeys {
                 tenant_id: key.tenant_id,
                 pks,
@@ -152,9 +148,7 @@ where
                 #[cfg(feature = "latency")]
                 gpu_sks: vec![csks.decompress_to_gpu()],
                 #[cfg(not(feature = "latency"))]
-                gpu_sks: (0..num_gpus)
-                    .map(|i| csks.decompress_to_specific_gpu(tfhe::GpuIndex::new(i as u32)))
-                    .collect::<Vec<_>>(),
+                gpu_sks: fhevm_engine_common::keys::decompress_to_all_gpus(&csks)?,
                 public_params: Arc::new(public_params),
                 chain_id: key.chain_id,
                 acl_contract_address: key.acl_contract_address,
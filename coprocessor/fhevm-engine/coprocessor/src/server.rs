@@ -17,8 +17,8 @@ use coprocessor::{
 };
 pub use fhevm_engine_common::common;
 use fhevm_engine_common::tfhe_ops::{
-    check_fhe_operand_types, current_ciphertext_version, trivial_encrypt_be_bytes,
-    try_expand_ciphertext_list, validate_fhe_type,
+    check_fhe_operand_types, check_fhe_operation_enabled, current_ciphertext_version,
+    trivial_encrypt_be_bytes, try_expand_ciphertext_list, validate_fhe_type,
 };
 use fhevm_engine_common::types::{FhevmError, SupportedFheCiphertexts, SupportedFheOperations};
 use lazy_static::lazy_static;
@@ -658,6 +658,9 @@ impl CoprocessorService {
                 }
             }
 
+            check_fhe_operation_enabled(comp.operation, &self.args.disabled_operations)
+                .map_err(CoprocessorError::FhevmError)?;
+
             // check before we insert computation that it has
             // to succeed according to the type system
             check_fhe_operand_types(comp.operation, &this_comp_inputs, &is_scalar_op_vec)
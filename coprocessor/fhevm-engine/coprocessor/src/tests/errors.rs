@@ -12,9 +12,13 @@ use crate::{
     },
     tests::{
         inputs::{test_random_contract_address, test_random_user_address},
-        utils::{default_api_key, default_tenant_id, setup_test_app},
+        utils::{
+            default_api_key, default_tenant_id, random_handle, setup_test_app,
+            setup_test_app_with_args,
+        },
     },
 };
+use fhevm_engine_common::types::SupportedFheOperations;
 use fhevm_engine_common::utils::safe_serialize;
 use tonic::metadata::MetadataValue;
 
@@ -643,3 +647,60 @@ async fn test_coprocessor_computation_errors() -> Result<(), Box<dyn std::error:
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_worker_refuses_disabled_operations() -> Result<(), Box<dyn std::error::Error>> {
+    let app = setup_test_app_with_args(|args| {
+        args.disabled_operations = vec![SupportedFheOperations::FheDiv];
+    })
+    .await?;
+    let pool = sqlx::postgres::PgPoolOptions::new()
+        .max_connections(2)
+        .connect(app.db_url())
+        .await?;
+
+    // inserted the way the listener does, bypassing the API checks
+    let output_handle = random_handle().to_be_bytes().to_vec();
+    let dependencies = vec![random_handle().to_be_bytes().to_vec(), vec![2]];
+    sqlx::query!(
+        "
+            INSERT INTO computations(tenant_id, output_handle, dependencies, fhe_operation, is_scalar)
+            VALUES($1, $2, $3, $4, true)
+        ",
+        default_tenant_id(),
+        &output_handle,
+        &dependencies,
+        SupportedFheOperations::FheDiv as i16,
+    )
+    .execute(&pool)
+    .await?;
+
+    let mut retries = 30;
+    let computation = loop {
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let computation = sqlx::query!(
+            "
+                SELECT is_completed, is_error, error_message
+                FROM computations
+                WHERE tenant_id = $1
+                AND output_handle = $2
+            ",
+            default_tenant_id(),
+            &output_handle,
+        )
+        .fetch_one(&pool)
+        .await?;
+        retries -= 1;
+        if computation.is_error || retries == 0 {
+            break computation;
+        }
+    };
+    assert!(computation.is_error, "disabled computation was not refused");
+    assert!(!computation.is_completed);
+    assert!(computation
+        .error_message
+        .unwrap_or_default()
+        .contains("(FheDiv) is disabled"));
+
+    Ok(())
+}
@@ -50,12 +50,20 @@ pub fn random_handle() -> u64 {
 }
 
 pub async fn setup_test_app() -> Result<TestInstance, Box<dyn std::error::Error>> {
+    setup_test_app_with_args(|_| {}).await
+}
+
+/// Same as [`setup_test_app`], with the coprocessor arguments adjusted by
+/// `customize`, which has no effect on an already running localhost app
+pub async fn setup_test_app_with_args(
+    customize: fn(&mut Args),
+) -> Result<TestInstance, Box<dyn std::error::Error>> {
     if std::env::var("COPROCESSOR_TEST_LOCALHOST").is_ok() {
         setup_test_app_existing_localhost().await
     } else if std::env::var("COPROCESSOR_TEST_LOCAL_DB").is_ok() {
-        setup_test_app_existing_db().await
+        setup_test_app_existing_db(customize).await
     } else {
-        setup_test_app_custom_docker().await
+        setup_test_app_custom_docker(customize).await
     }
 }
 
@@ -71,10 +79,12 @@ pub async fn setup_test_app_existing_localhost() -> Result<TestInstance, Box<dyn
     })
 }
 
-async fn setup_test_app_existing_db() -> Result<TestInstance, Box<dyn std::error::Error>> {
+async fn setup_test_app_existing_db(
+    customize: fn(&mut Args),
+) -> Result<TestInstance, Box<dyn std::error::Error>> {
     let app_port = get_app_port();
     let (app_close_channel, rx) = tokio::sync::watch::channel(false);
-    start_coprocessor(rx, app_port, LOCAL_DB_URL).await;
+    start_coprocessor(rx, app_port, LOCAL_DB_URL, customize).await;
     Ok(TestInstance {
         _container: None,
         app_close_channel: Some(app_close_channel),
@@ -83,8 +93,13 @@ async fn setup_test_app_existing_db() -> Result<TestInstance, Box<dyn std::error
     })
 }
 
-async fn start_coprocessor(rx: Receiver<bool>, app_port: u16, db_url: &str) {
-    let args: Args = Args {
+async fn start_coprocessor(
+    rx: Receiver<bool>,
+    app_port: u16,
+    db_url: &str,
+    customize: fn(&mut Args),
+) {
+    let mut args: Args = Args {
         run_bg_worker: true,
         worker_polling_interval_ms: 1000,
         run_server: true,
@@ -95,6 +110,7 @@ async fn start_coprocessor(rx: Receiver<bool>, app_port: u16, db_url: &str) {
         tenant_key_cache_size: 4,
         coprocessor_fhe_threads: 4,
         maximum_handles_per_input: 255,
+        disabled_operations: Vec::new(),
         tokio_threads: 2,
         pg_pool_max_connections: 2,
         server_addr: format!("127.0.0.1:{app_port}"),
@@ -105,6 +121,7 @@ async fn start_coprocessor(rx: Receiver<bool>, app_port: u16, db_url: &str) {
         service_name: "coprocessor".to_string(),
         log_level: Level::INFO,
     };
+    customize(&mut args);
 
     std::thread::spawn(move || {
         crate::start_runtime(args, Some(rx));
@@ -125,7 +142,9 @@ fn get_app_port() -> u16 {
     app_port
 }
 
-async fn setup_test_app_custom_docker() -> Result<TestInstance, Box<dyn std::error::Error>> {
+async fn setup_test_app_custom_docker(
+    customize: fn(&mut Args),
+) -> Result<TestInstance, Box<dyn std::error::Error>> {
     let app_port = get_app_port();
 
     let container = GenericImage::new("postgres", "15.7")
@@ -163,7 +182,7 @@ async fn setup_test_app_custom_docker() -> Result<TestInstance, Box<dyn std::err
     println!("DB prepared");
 
     let (app_close_channel, rx) = tokio::sync::watch::channel(false);
-    start_coprocessor(rx, app_port, &db_url).await;
+    start_coprocessor(rx, app_port, &db_url, customize).await;
     Ok(TestInstance {
         _container: Some(container),
         app_close_channel: Some(app_close_channel),
@@ -1,7 +1,9 @@
 use crate::types::CoprocessorError;
 use crate::{db_queries::populate_cache_with_tenant_keys, types::TfheTenantKeys};
-use fhevm_engine_common::types::{FhevmError, Handle, SupportedFheCiphertexts};
-use fhevm_engine_common::{tfhe_ops::current_ciphertext_version, types::SupportedFheOperations};
+use fhevm_engine_common::tfhe_ops::{check_fhe_operation_enabled, current_ciphertext_version};
+use fhevm_engine_common::types::{
+    FhevmError, Handle, SupportedFheCiphertexts, SupportedFheOperations,
+};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use opentelemetry::trace::{Span, TraceContextExt, Tracer};
@@ -136,6 +138,32 @@ async fn tfhe_worker_cycle(
             continue;
         }
         WORK_ITEMS_FOUND_COUNTER.inc_by(the_work.len() as u64);
+        // Computations inserted by the listener bypass the API checks,
+        // refuse disabled operations before loading any keys
+        let mut the_work = the_work;
+        let mut disabled_work = Vec::new();
+        the_work.retain(|w| {
+            match check_fhe_operation_enabled(w.fhe_operation.into(), &args.disabled_operations) {
+                Err(err @ FhevmError::FheOperationDisabled { .. }) => {
+                    disabled_work.push((w.tenant_id, w.output_handle.clone(), err));
+                    false
+                }
+                // unknown operations fail in perform_fhe_operation like before
+                _ => true,
+            }
+        });
+        for (tenant_id, output_handle, err) in disabled_work {
+            WORK_ITEMS_ERRORS_COUNTER.inc();
+            warn!(target: "tfhe_worker",
+                { tenant_id = tenant_id, error = %err, output_handle = format!("0x{}", hex::encode(&output_handle)) },
+                "refusing disabled operation"
+            );
+            set_computation_error(&mut trx, &err.to_string(), tenant_id, &output_handle).await?;
+        }
+        if the_work.is_empty() {
+            trx.commit().await?;
+            continue;
+        }
         info!(target: "tfhe_worker", { count = the_work.len() }, "Processing work items");
         // Make sure we process each tenant independently to avoid
         // setting different keys from different tenants in the worker
@@ -469,19 +497,8 @@ async fn tfhe_worker_cycle(
                         s.set_status(opentelemetry::trace::Status::Error {
                             description: err_string.clone().into(),
                         });
-                        let _ = query!(
-                            "
-                            UPDATE computations
-                            SET is_error = true, error_message = $1
-                            WHERE tenant_id = $2
-                            AND output_handle = $3
-                        ",
-                            err_string,
-                            tenant_id,
-                            output_handle
-                        )
-                        .execute(trx.as_mut())
-                        .await?;
+                        set_computation_error(&mut trx, &err_string, tenant_id, &output_handle)
+                            .await?;
                         s.end();
                     }
                 }
@@ -504,3 +521,25 @@ async fn tfhe_worker_cycle(
         }
     }
 }
+
+async fn set_computation_error(
+    trx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    error_message: &str,
+    tenant_id: i32,
+    output_handle: &[u8],
+) -> Result<(), sqlx::Error> {
+    let _ = query!(
+        "
+            UPDATE computations
+            SET is_error = true, error_message = $1
+            WHERE tenant_id = $2
+            AND output_handle = $3
+        ",
+        error_message,
+        tenant_id,
+        output_handle
+    )
+    .execute(trx.as_mut())
+    .await?;
+    Ok(())
+}
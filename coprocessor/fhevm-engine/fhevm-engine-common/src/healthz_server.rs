@@ -6,16 +6,32 @@ use axum::{
 };
 use serde::Serialize;
 use sqlx::PgPool;
-use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    net::SocketAddr,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use tokio::net::TcpListener;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info};
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthLevel {
+    Healthy,
+    /// Only non-fatal checks are failing
+    Degraded,
+    Unhealthy,
+}
+
 #[derive(Serialize)]
 struct HealthResponse {
     status_code: String,
     status: String,
+    level: HealthLevel,
     dependencies: HashMap<&'static str, &'static str>,
+    last_ok_unix: HashMap<&'static str, u64>,
     details: String,
 }
 
@@ -35,7 +51,9 @@ impl From<HealthStatus> for HealthResponse {
             } else {
                 "unhealthy".to_string()
             },
+            level: status.level(),
             dependencies,
+            last_ok_unix: status.last_ok_unix,
             details,
         }
     }
@@ -48,10 +66,22 @@ pub struct Version {
     pub build: &'static str,
 }
 
+/// Effective health check configuration of a running service
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct HealthCheckConfigSnapshot {
+    /// Seconds of inactivity after which the service is no longer alive
+    pub liveness_threshold_secs: Option<u64>,
+    /// Number of workers whose activity is checked individually
+    pub tracked_workers: usize,
+}
+
 pub trait HealthCheckService: Send + Sync {
     fn health_check(&self) -> impl std::future::Future<Output = HealthStatus> + Send;
     fn is_alive(&self) -> impl std::future::Future<Output = bool> + Send;
     fn get_version(&self) -> Version;
+    fn get_config(&self) -> HealthCheckConfigSnapshot {
+        HealthCheckConfigSnapshot::default()
+    }
 }
 
 pub struct HttpServer<S: HealthCheckService + Send + Sync + 'static> {
@@ -71,6 +101,7 @@ impl<S: HealthCheckService + Send + Sync + 'static> HttpServer<S> {
     pub async fn start(&self) -> anyhow::Result<()> {
         let app = Router::new()
             .route("/healthz", get(Self::health_handler))
+            .route("/healthz/config", get(Self::config_handler))
             .route("/liveness", get(Self::liveness_handler))
             .route("/version", get(Self::version_handler))
             .with_state(self.service.clone());
@@ -128,6 +159,10 @@ impl<S: HealthCheckService + Send + Sync + 'static> HttpServer<S> {
         }
     }
 
+    async fn config_handler(State(service): State<Arc<S>>) -> impl IntoResponse {
+        (StatusCode::OK, Json(service.get_config()))
+    }
+
     async fn version_handler(State(service): State<Arc<S>>) -> impl IntoResponse {
         let version = service.get_version();
         (StatusCode::OK, Json(serde_json::json!(version)))
@@ -137,6 +172,10 @@ impl<S: HealthCheckService + Send + Sync + 'static> HttpServer<S> {
 #[derive(Clone, Default)]
 pub struct HealthStatus {
     checks: HashMap<&'static str, bool>,
+    // checks whose failure degrades the service without making it unhealthy
+    non_fatal_checks: HashSet<&'static str>,
+    // unix seconds of the last time each check was known to pass
+    last_ok_unix: HashMap<&'static str, u64>,
     error_details: Vec<String>,
 }
 
@@ -145,6 +184,14 @@ impl HealthStatus {
     ///
     /// query has its internal timeout
     pub async fn set_db_connected(&mut self, pool: &PgPool) {
+        self.set_db_pool_connected("database", pool).await;
+    }
+
+    /// Same as [`Self::set_db_connected`] for services using several pools,
+    /// each reported under its own check, e.g. `database:readpool`. Services
+    /// with a single pool report it as `database` through
+    /// [`Self::set_db_connected`]
+    pub async fn set_db_pool_connected(&mut self, check: &'static str, pool: &PgPool) {
         let mut is_connected = false;
         match sqlx::query("SELECT 1").execute(pool).await {
             Ok(_) => {
@@ -155,11 +202,45 @@ impl HealthStatus {
                     .push(format!("Database query error: {}", e));
             }
         }
-        self.checks.insert("database", is_connected);
+        self.set_check(check, is_connected);
     }
 
     pub fn set_custom_check(&mut self, check: &'static str, value: bool) {
+        self.set_check(check, value);
+    }
+
+    /// Same as [`Self::set_custom_check`] for a check whose failure only
+    /// degrades the service, see [`HealthLevel::Degraded`]
+    pub fn set_non_fatal_check(&mut self, check: &'static str, value: bool) {
+        self.non_fatal_checks.insert(check);
+        self.set_check(check, value);
+    }
+
+    /// Records the last success time of a check for services tracking it
+    /// themselves, e.g. the oldest worker activity, so a failing check still
+    /// tells how long it has been failing. Takes precedence over the time the
+    /// check is set at, whatever the call order
+    pub fn set_last_ok_at(&mut self, check: &'static str, at: SystemTime) {
+        self.last_ok_unix.insert(check, unix_secs(at));
+    }
+
+    /// Whether the check passed, `None` if it was not set
+    pub fn check(&self, check: &str) -> Option<bool> {
+        self.checks.get(check).copied()
+    }
+
+    /// Unix seconds of the last success of the check, if known
+    pub fn last_ok_unix(&self, check: &str) -> Option<u64> {
+        self.last_ok_unix.get(check).copied()
+    }
+
+    fn set_check(&mut self, check: &'static str, value: bool) {
         self.checks.insert(check, value);
+        if value {
+            self.last_ok_unix
+                .entry(check)
+                .or_insert_with(|| unix_secs(SystemTime::now()));
+        }
     }
 
     pub fn add_error_details(&mut self, details: String) {
@@ -167,7 +248,21 @@ impl HealthStatus {
     }
 
     pub fn is_healthy(&self) -> bool {
-        self.checks.iter().all(|(_, s)| *s)
+        self.level() != HealthLevel::Unhealthy
+    }
+
+    pub fn level(&self) -> HealthLevel {
+        let mut level = HealthLevel::Healthy;
+        for (check, &ok) in &self.checks {
+            if ok {
+                continue;
+            }
+            if !self.non_fatal_checks.contains(check) {
+                return HealthLevel::Unhealthy;
+            }
+            level = HealthLevel::Degraded;
+        }
+        level
     }
 
     pub fn error_details(&self) -> String {
@@ -179,3 +274,125 @@ impl HealthStatus {
             .join("; ")
     }
 }
+
+fn unix_secs(at: SystemTime) -> u64 {
+    at.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn serialized_status_has_per_check_timestamps() {
+        let stale = SystemTime::now() - Duration::from_secs(600);
+        let mut status = HealthStatus::default();
+        status.set_custom_check("s3_buckets", true);
+        // a service-provided time is kept whether set before or after the check
+        status.set_last_ok_at("blockchain", stale);
+        status.set_custom_check("blockchain", true);
+        status.set_custom_check("workers", false);
+        status.set_last_ok_at("workers", stale);
+        status.set_custom_check("s3_connection", false);
+
+        let json = serde_json::to_value(HealthResponse::from(status)).unwrap();
+        let last_ok = &json["last_ok_unix"];
+        assert!(last_ok["s3_buckets"].as_u64().unwrap() >= unix_secs(stale) + 600);
+        assert_eq!(last_ok["blockchain"], unix_secs(stale));
+        assert_eq!(last_ok["workers"], unix_secs(stale));
+        assert!(last_ok.get("s3_connection").is_none());
+    }
+
+    struct ConfiguredService;
+
+    impl HealthCheckService for ConfiguredService {
+        async fn health_check(&self) -> HealthStatus {
+            HealthStatus::default()
+        }
+
+        async fn is_alive(&self) -> bool {
+            true
+        }
+
+        fn get_version(&self) -> Version {
+            Version {
+                name: "test",
+                version: "unknown",
+                build: "unknown",
+            }
+        }
+
+        fn get_config(&self) -> HealthCheckConfigSnapshot {
+            HealthCheckConfigSnapshot {
+                liveness_threshold_secs: Some(70),
+                tracked_workers: 4,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn config_reflects_service_thresholds() {
+        let response =
+            HttpServer::<ConfiguredService>::config_handler(State(Arc::new(ConfiguredService)))
+                .await
+                .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["liveness_threshold_secs"], 70);
+        assert_eq!(json["tracked_workers"], 4);
+    }
+
+    #[tokio::test]
+    async fn db_pools_are_reported_separately() {
+        let instance = test_harness::instance::setup_test_db()
+            .await
+            .expect("valid db instance");
+        let read_pool = PgPool::connect(instance.db_url())
+            .await
+            .expect("valid db pool");
+        // nothing listens on port 1
+        let write_pool = sqlx::postgres::PgPoolOptions::new()
+            .acquire_timeout(Duration::from_secs(1))
+            .connect_lazy("postgres://postgres@127.0.0.1:1/coprocessor")
+            .unwrap();
+
+        let mut status = HealthStatus::default();
+        status
+            .set_db_pool_connected("database:readpool", &read_pool)
+            .await;
+        status
+            .set_db_pool_connected("database:writepool", &write_pool)
+            .await;
+
+        assert_eq!(status.check("database:readpool"), Some(true));
+        assert_eq!(status.check("database:writepool"), Some(false));
+        assert!(!status.is_healthy());
+        assert!(status.error_details().starts_with("Database query error: "));
+    }
+
+    #[test]
+    fn non_fatal_failures_degrade() {
+        let mut status = HealthStatus::default();
+        status.set_custom_check("database", true);
+        status.set_non_fatal_check("s3_buckets", true);
+        assert_eq!(status.level(), HealthLevel::Healthy);
+
+        status.set_non_fatal_check("s3_connection", false);
+        assert_eq!(status.level(), HealthLevel::Degraded);
+        assert!(status.is_healthy());
+        let json = serde_json::to_value(HealthResponse::from(status.clone())).unwrap();
+        assert_eq!(json["level"], "degraded");
+        assert_eq!(json["status_code"], "200");
+
+        status.set_custom_check("database", false);
+        assert_eq!(status.level(), HealthLevel::Unhealthy);
+        assert!(!status.is_healthy());
+    }
+}
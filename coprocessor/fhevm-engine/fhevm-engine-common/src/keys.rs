@@ -15,7 +15,10 @@ use tfhe::{
     ClientKey, CompactPublicKey, CompressedServerKey, Config, ConfigBuilder, ServerKey,
 };
 
-use crate::utils::{safe_deserialize_key, safe_serialize_key};
+use crate::{
+    types::FhevmError,
+    utils::{safe_deserialize_key, safe_serialize_key},
+};
 
 pub const TFHE_COMPRESSION_PARAMS: CompressionParameters =
     V1_0_COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
@@ -81,9 +84,8 @@ impl FhevmKeys {
             gpu_server_key: vec![compressed_server_key.decompress_to_gpu()],
             #[cfg(feature = "gpu")]
             #[cfg(not(feature = "latency"))]
-            gpu_server_key: (0..get_number_of_gpus() as u32)
-                .map(|i| compressed_server_key.decompress_to_specific_gpu(tfhe::GpuIndex::new(i)))
-                .collect::<Vec<_>>(),
+            gpu_server_key: decompress_to_all_gpus(&compressed_server_key)
+                .expect("decompress server key to GPUs"),
         }
     }
 
@@ -100,12 +102,39 @@ impl FhevmKeys {
     pub fn set_server_key_for_current_thread(&self) {
         set_server_key(self.server_key.clone());
     }
-    pub fn set_gpu_server_key_for_current_thread(&self) {
+    pub fn set_gpu_server_key_for_current_thread(&self) -> Result<(), FhevmError> {
         #[cfg(feature = "gpu")]
-        set_server_key(self.gpu_server_key[0].clone());
+        set_server_key(
+            self.gpu_server_key
+                .first()
+                .ok_or(FhevmError::NoGpusAvailable)?
+                .clone(),
+        );
         #[cfg(not(feature = "gpu"))]
         set_server_key(self.server_key.clone());
+        Ok(())
+    }
+}
+
+/// Decompresses the server key on every visible GPU
+#[cfg(feature = "gpu")]
+pub fn decompress_to_all_gpus(
+    csks: &CompressedServerKey,
+) -> Result<Vec<tfhe::CudaServerKey>, FhevmError> {
+    decompress_to_gpus(csks, get_number_of_gpus())
+}
+
+#[cfg(feature = "gpu")]
+fn decompress_to_gpus(
+    csks: &CompressedServerKey,
+    gpu_count: u32,
+) -> Result<Vec<tfhe::CudaServerKey>, FhevmError> {
+    if gpu_count == 0 {
+        return Err(FhevmError::NoGpusAvailable);
     }
+    Ok((0..gpu_count)
+        .map(|i| csks.decompress_to_specific_gpu(tfhe::GpuIndex::new(i)))
+        .collect())
 }
 
 impl SerializedFhevmKeys {
@@ -230,11 +259,38 @@ impl From<SerializedFhevmKeys> for FhevmKeys {
             gpu_server_key: vec![compressed_server_key.decompress_to_gpu()],
             #[cfg(feature = "gpu")]
             #[cfg(not(feature = "latency"))]
-            gpu_server_key: (0..get_number_of_gpus() as u32)
-                .map(|i| compressed_server_key.decompress_to_specific_gpu(tfhe::GpuIndex::new(i)))
-                .collect::<Vec<_>>(),
+            gpu_server_key: decompress_to_all_gpus(&compressed_server_key)
+                .expect("decompress server key to GPUs"),
             #[cfg(feature = "gpu")]
             server_key: compressed_server_key.decompress(),
         }
     }
 }
+
+#[cfg(all(test, feature = "gpu"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompression_requires_a_gpu() {
+        let client_key = ClientKey::generate(FhevmKeys::new_config());
+        let csks = CompressedServerKey::new(&client_key);
+        assert!(matches!(
+            decompress_to_gpus(&csks, 0),
+            Err(FhevmError::NoGpusAvailable)
+        ));
+        let gpu_server_key = decompress_to_all_gpus(&csks).unwrap();
+        assert_eq!(gpu_server_key.len(), get_number_of_gpus() as usize);
+    }
+
+    #[test]
+    fn setting_a_gpu_key_requires_a_gpu() {
+        let mut keys = FhevmKeys::new();
+        keys.set_gpu_server_key_for_current_thread().unwrap();
+        keys.gpu_server_key.clear();
+        assert!(matches!(
+            keys.set_gpu_server_key_for_current_thread(),
+            Err(FhevmError::NoGpusAvailable)
+        ));
+    }
+}
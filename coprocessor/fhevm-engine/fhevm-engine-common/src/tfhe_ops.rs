@@ -1,6 +1,9 @@
 use crate::{
     keys::FhevmKeys,
-    types::{FheOperationType, FhevmError, SupportedFheCiphertexts, SupportedFheOperations},
+    types::{
+        is_ebytes_type, is_valid_type_tag, FheOperationType, FhevmError, SupportedFheCiphertexts,
+        SupportedFheOperations,
+    },
     utils::{safe_deserialize, safe_deserialize_conformant},
 };
 use tfhe::{
@@ -436,6 +439,7 @@ pub fn check_fhe_operand_types(
     let fhe_op: SupportedFheOperations = fhe_operation.try_into()?;
 
     assert_eq!(input_handles.len(), is_input_handle_scalar.len());
+    fhe_op.check_arity(input_handles.len())?;
 
     let scalar_operands = is_input_handle_scalar
         .iter()
@@ -485,16 +489,6 @@ pub fn check_fhe_operand_types(
 
     match fhe_op.op_type() {
         FheOperationType::Binary => {
-            let expected_operands = 2;
-            if input_handles.len() != expected_operands {
-                return Err(FhevmError::UnexpectedOperandCountForFheOperation {
-                    fhe_operation,
-                    fhe_operation_name: format!("{:?}", fhe_op),
-                    expected_operands,
-                    got_operands: input_handles.len(),
-                });
-            }
-
             // special case for div operation, rhs for scalar must not be zero
             if is_scalar && fhe_op == SupportedFheOperations::FheDiv {
                 let all_zeroes = input_handles[1].iter().all(|i| *i == 0u8);
@@ -510,47 +504,13 @@ pub fn check_fhe_operand_types(
 
             Ok(())
         }
-        FheOperationType::Unary => {
-            let expected_operands = 1;
-            if input_handles.len() != expected_operands {
-                return Err(FhevmError::UnexpectedOperandCountForFheOperation {
-                    fhe_operation,
-                    fhe_operation_name: format!("{:?}", fhe_op),
-                    expected_operands,
-                    got_operands: input_handles.len(),
-                });
-            }
-
-            Ok(())
-        }
+        FheOperationType::Unary => Ok(()),
         FheOperationType::Other => {
             match &fhe_op {
                 // two ops + uniform types branch
                 // what about scalar compute?
-                SupportedFheOperations::FheIfThenElse => {
-                    let expected_operands = 3;
-                    if input_handles.len() != expected_operands {
-                        return Err(FhevmError::UnexpectedOperandCountForFheOperation {
-                            fhe_operation,
-                            fhe_operation_name: format!("{:?}", fhe_op),
-                            expected_operands,
-                            got_operands: input_handles.len(),
-                        });
-                    }
-
-                    Ok(())
-                }
+                SupportedFheOperations::FheIfThenElse => Ok(()),
                 SupportedFheOperations::FheCast => {
-                    let expected_operands = 2;
-                    if input_handles.len() != expected_operands {
-                        return Err(FhevmError::UnexpectedOperandCountForFheOperation {
-                            fhe_operation,
-                            fhe_operation_name: format!("{:?}", fhe_op),
-                            expected_operands,
-                            got_operands: input_handles.len(),
-                        });
-                    }
-
                     match (is_input_handle_scalar[0], is_input_handle_scalar[1]) {
                         (false, true) => {
                             let op = &input_handles[1];
@@ -587,16 +547,6 @@ pub fn check_fhe_operand_types(
                     }
                 }
                 SupportedFheOperations::FheTrivialEncrypt => {
-                    let expected_operands = 2;
-                    if input_handles.len() != expected_operands {
-                        return Err(FhevmError::UnexpectedOperandCountForFheOperation {
-                            fhe_operation,
-                            fhe_operation_name: format!("{:?}", fhe_op),
-                            expected_operands,
-                            got_operands: input_handles.len(),
-                        });
-                    }
-
                     if !is_input_handle_scalar[0] || !is_input_handle_scalar[1] {
                         return Err(FhevmError::AllInputsForTrivialEncryptionMustBeScalar {
                             fhe_operation,
@@ -616,27 +566,17 @@ pub fn check_fhe_operand_types(
                         );
                     }
 
-                    Ok(())
+                    validate_fhe_type(op[0] as i32)
                 }
                 SupportedFheOperations::FheRand => {
                     // counter and output type
-                    let expected_operands = 2;
-                    if input_handles.len() != expected_operands {
-                        return Err(FhevmError::UnexpectedOperandCountForFheOperation {
-                            fhe_operation,
-                            fhe_operation_name: format!("{:?}", fhe_op),
-                            expected_operands,
-                            got_operands: input_handles.len(),
-                        });
-                    }
-
                     let scalar_operands = is_input_handle_scalar.iter().filter(|i| **i).count();
-                    if scalar_operands < expected_operands {
+                    if scalar_operands < input_handles.len() {
                         return Err(FhevmError::RandOperationInputsMustAllBeScalar {
                             fhe_operation,
                             fhe_operation_name: format!("{:?}", fhe_op),
                             scalar_operand_count: scalar_operands,
-                            expected_scalar_operand_count: expected_operands,
+                            expected_scalar_operand_count: input_handles.len(),
                         });
                     }
 
@@ -656,23 +596,13 @@ pub fn check_fhe_operand_types(
                 }
                 SupportedFheOperations::FheRandBounded => {
                     // counter, bound and output type
-                    let expected_operands = 3;
-                    if input_handles.len() != expected_operands {
-                        return Err(FhevmError::UnexpectedOperandCountForFheOperation {
-                            fhe_operation,
-                            fhe_operation_name: format!("{:?}", fhe_op),
-                            expected_operands,
-                            got_operands: input_handles.len(),
-                        });
-                    }
-
                     let scalar_operands = is_input_handle_scalar.iter().filter(|i| **i).count();
-                    if scalar_operands < expected_operands {
+                    if scalar_operands < input_handles.len() {
                         return Err(FhevmError::RandOperationInputsMustAllBeScalar {
                             fhe_operation,
                             fhe_operation_name: format!("{:?}", fhe_op),
                             scalar_operand_count: scalar_operands,
-                            expected_scalar_operand_count: expected_operands,
+                            expected_scalar_operand_count: input_handles.len(),
                         });
                     }
 
@@ -709,9 +639,10 @@ pub fn validate_fhe_type(input_type: i32) -> Result<(), FhevmError> {
     let i16_type: i16 = input_type
         .try_into()
         .or(Err(FhevmError::UnknownFheType(input_type)))?;
-    match i16_type {
-        0..=11 => Ok(()),
-        _ => Err(FhevmError::UnknownFheType(input_type)),
+    if is_valid_type_tag(i16_type) {
+        Ok(())
+    } else {
+        Err(FhevmError::UnknownFheType(input_type))
     }
 }
 
@@ -734,16 +665,151 @@ pub fn does_fhe_operation_support_both_encrypted_operands(op: &SupportedFheOpera
     !matches!(op, SupportedFheOperations::FheDiv)
 }
 
+/// Errors if the operation is one of those disabled by configuration
+pub fn check_fhe_operation_enabled(
+    fhe_operation: i32,
+    disabled_operations: &[SupportedFheOperations],
+) -> Result<(), FhevmError> {
+    let fhe_op: SupportedFheOperations = fhe_operation.try_into()?;
+    if disabled_operations.contains(&fhe_op) {
+        return Err(FhevmError::FheOperationDisabled {
+            fhe_operation,
+            fhe_operation_name: format!("{:?}", fhe_op),
+        });
+    }
+
+    Ok(())
+}
+
+/// Whether [`perform_fhe_operation`] supports operands of the given type
+/// tags, scalar operands being tagged with the scalar type number, without
+/// evaluating anything
+pub fn is_supported(fhe_operation_int: i16, operand_tags: &[i16]) -> bool {
+    let Ok(fhe_operation) = SupportedFheOperations::try_from(fhe_operation_int) else {
+        return false;
+    };
+    if fhe_operation.check_arity(operand_tags.len()).is_err() {
+        return false;
+    }
+
+    let scalar = SupportedFheCiphertexts::Scalar(Vec::new()).type_num();
+    let is_input_type = |tag: i16| {
+        is_valid_type_tag(tag)
+            && (tag != 0 || fhe_operation.supports_bool_inputs())
+            && (!is_ebytes_type(tag) || fhe_operation.supports_ebytes_inputs())
+    };
+    match fhe_operation.op_type() {
+        FheOperationType::Binary => {
+            is_input_type(operand_tags[0])
+                && (operand_tags[1] == operand_tags[0] || operand_tags[1] == scalar)
+        }
+        FheOperationType::Unary => is_input_type(operand_tags[0]),
+        FheOperationType::Other => match fhe_operation {
+            SupportedFheOperations::FheIfThenElse => {
+                operand_tags[0] == 0
+                    && is_valid_type_tag(operand_tags[1])
+                    && operand_tags[1] == operand_tags[2]
+            }
+            // second operand is the type to cast to
+            SupportedFheOperations::FheCast => {
+                is_valid_type_tag(operand_tags[0]) && operand_tags[1] == scalar
+            }
+            SupportedFheOperations::FheTrivialEncrypt
+            | SupportedFheOperations::FheRand
+            | SupportedFheOperations::FheRandBounded => {
+                operand_tags.iter().all(|tag| *tag == scalar)
+            }
+            _ => false,
+        },
+    }
+}
+
+/// Type tag of the result of an operation on operands of the given type
+/// tags, without evaluating it
+///
+/// Returns `None` for unsupported operands and for operations whose output
+/// type is given by a scalar value rather than an operand type, like casts
+pub fn output_type_of(fhe_operation_int: i16, operand_tags: &[i16]) -> Option<i16> {
+    if !is_supported(fhe_operation_int, operand_tags) {
+        return None;
+    }
+    let fhe_operation = SupportedFheOperations::try_from(fhe_operation_int).ok()?;
+    if fhe_operation.is_comparison() {
+        return Some(0);
+    }
+    match fhe_operation.op_type() {
+        // min/max compare internally but return one of the operands, and
+        // arithmetic wraps, so a product is as wide as its operands
+        FheOperationType::Binary | FheOperationType::Unary => Some(operand_tags[0]),
+        FheOperationType::Other => match fhe_operation {
+            SupportedFheOperations::FheIfThenElse => Some(operand_tags[1]),
+            _ => None,
+        },
+    }
+}
+
+fn type_bit_width(tag: i16) -> Option<u64> {
+    match tag {
+        0 => Some(1),
+        1 => Some(4),
+        2 => Some(8),
+        3 => Some(16),
+        4 => Some(32),
+        5 => Some(64),
+        6 => Some(128),
+        7 => Some(160),
+        8 => Some(256),
+        9 => Some(512),
+        10 => Some(1024),
+        11 => Some(2048),
+        _ => None,
+    }
+}
+
+/// Relative latency weight of an operation on operands of the given type
+/// tags, to order work rather than predict actual timings
+///
+/// Scales with the widest encrypted operand, division and remainder being
+/// the heaviest, then multiplication, then comparisons, min/max, shifts and
+/// rotations, and finally additions, bitwise and other operations
+///
+/// Width scales linearly, like the number of radix blocks. The per operation
+/// factors are coarse steps keeping these classes apart at equal width, not
+/// measured ratios
+pub fn estimate_op_latency_weight(fhe_operation_int: i16, operand_tags: &[i16]) -> u64 {
+    let Ok(fhe_operation) = SupportedFheOperations::try_from(fhe_operation_int) else {
+        return 0;
+    };
+    let bits = operand_tags
+        .iter()
+        .filter_map(|tag| type_bit_width(*tag))
+        .max()
+        .unwrap_or(1);
+    let op_weight = match fhe_operation {
+        SupportedFheOperations::FheDiv | SupportedFheOperations::FheRem => 64,
+        SupportedFheOperations::FheMul => 16,
+        SupportedFheOperations::FheMin
+        | SupportedFheOperations::FheMax
+        | SupportedFheOperations::FheShl
+        | SupportedFheOperations::FheShr
+        | SupportedFheOperations::FheRotl
+        | SupportedFheOperations::FheRotr => 4,
+        op if op.is_comparison() => 4,
+        _ => 1,
+    };
+    op_weight * bits
+}
+
 pub fn perform_fhe_operation(
     fhe_operation_int: i16,
     input_operands: &[SupportedFheCiphertexts],
     // for deterministc randomness functions
 ) -> Result<SupportedFheCiphertexts, FhevmError> {
     let fhe_operation: SupportedFheOperations = fhe_operation_int.try_into()?;
+    // validate before any arm indexes into the operands
+    fhe_operation.check_arity(input_operands.len())?;
     match fhe_operation {
         SupportedFheOperations::FheAdd => {
-            assert_eq!(input_operands.len(), 2);
-
             // fhe add
             match (&input_operands[0], &input_operands[1]) {
                 (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
@@ -803,1461 +869,1281 @@ pub fn perform_fhe_operation(
                 }),
             }
         }
-        SupportedFheOperations::FheSub => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a - b))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a - b))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a - b))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a - b))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a - b))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a - b)),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a - b)),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a - b)),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a - to_be_u4_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a - to_be_u8_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a - to_be_u16_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a - to_be_u32_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a - to_be_u64_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(a - to_be_u128_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(a - to_be_u160_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(a - to_be_u256_bit(b)))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+        SupportedFheOperations::FheSub => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a - b))
             }
-        }
-        SupportedFheOperations::FheMul => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a * b))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a * b))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a * b))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a * b))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a * b))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a * b)),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a * b)),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a * b)),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a * to_be_u4_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a * to_be_u8_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a * to_be_u16_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a * to_be_u32_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a * to_be_u64_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(a * to_be_u128_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(a * to_be_u160_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(a * to_be_u256_bit(b)))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a - b))
             }
-        }
-        SupportedFheOperations::FheDiv => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a / b))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a / b))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a / b))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a / b))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a / b))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a / b)),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a / b)),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a / b)),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a / to_be_u4_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a / to_be_u8_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a / to_be_u16_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a / to_be_u32_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a / to_be_u64_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(a / to_be_u128_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(a / to_be_u160_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(a / to_be_u256_bit(b)))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a - b))
             }
-        }
-        SupportedFheOperations::FheRem => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a % b))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a % b))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a % b))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a % b))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a % b))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a % b)),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a % b)),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a % b)),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a % to_be_u4_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a % to_be_u8_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a % to_be_u16_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a % to_be_u32_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a % to_be_u64_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(a % to_be_u128_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(a % to_be_u160_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(a % to_be_u256_bit(b)))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a - b))
             }
-        }
-        SupportedFheOperations::FheBitAnd => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a & b))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a & b))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a & b))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a & b))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a & b))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a & b))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a & b)),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a & b)),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a & b)),
-                (
-                    SupportedFheCiphertexts::FheBytes128(a),
-                    SupportedFheCiphertexts::FheBytes128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes128(a & b)),
-                (
-                    SupportedFheCiphertexts::FheBytes256(a),
-                    SupportedFheCiphertexts::FheBytes256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes256(a & b)),
-                (
-                    SupportedFheCiphertexts::FheBytes64(a),
-                    SupportedFheCiphertexts::FheBytes64(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes64(a & b)),
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a & (to_be_u4_bit(b) > 0)))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a & to_be_u4_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a & to_be_u8_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a & to_be_u16_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a & to_be_u32_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a & to_be_u64_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(a & to_be_u128_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(a & to_be_u160_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(a & to_be_u256_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes64(a & to_be_u512_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes128(a & to_be_u1024_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes256(a & to_be_u2048_bit(b)))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a - b))
             }
-        }
-        SupportedFheOperations::FheBitOr => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a | b))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a | b))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a | b))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a | b))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a | b))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a | b))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a | b)),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a | b)),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a | b)),
-                (
-                    SupportedFheCiphertexts::FheBytes64(a),
-                    SupportedFheCiphertexts::FheBytes64(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes64(a | b)),
-                (
-                    SupportedFheCiphertexts::FheBytes128(a),
-                    SupportedFheCiphertexts::FheBytes128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes128(a | b)),
-                (
-                    SupportedFheCiphertexts::FheBytes256(a),
-                    SupportedFheCiphertexts::FheBytes256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes256(a | b)),
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a | (to_be_u4_bit(b) > 0)))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a | to_be_u4_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a | to_be_u8_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a | to_be_u16_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a | to_be_u32_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a | to_be_u64_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(a | to_be_u128_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(a | to_be_u160_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(a | to_be_u256_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes64(a | to_be_u512_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes128(a | to_be_u1024_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes256(a | to_be_u2048_bit(b)))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a - b))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a - b))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a - b))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a - to_be_u4_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a - to_be_u8_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a - to_be_u16_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a - to_be_u32_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a - to_be_u64_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a - to_be_u128_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a - to_be_u160_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a - to_be_u256_bit(b)))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheMul => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a * b))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a * b))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a * b))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a * b))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a * b))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a * b))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a * b))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a * b))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a * to_be_u4_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a * to_be_u8_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a * to_be_u16_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a * to_be_u32_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a * to_be_u64_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a * to_be_u128_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a * to_be_u160_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a * to_be_u256_bit(b)))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheDiv => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a / b))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a / b))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a / b))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a / b))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a / b))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a / b))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a / b))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a / b))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a / to_be_u4_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a / to_be_u8_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a / to_be_u16_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a / to_be_u32_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a / to_be_u64_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a / to_be_u128_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a / to_be_u160_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a / to_be_u256_bit(b)))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheRem => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a % b))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a % b))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a % b))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a % b))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a % b))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a % b))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a % b))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a % b))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a % to_be_u4_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a % to_be_u8_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a % to_be_u16_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a % to_be_u32_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a % to_be_u64_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a % to_be_u128_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a % to_be_u160_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a % to_be_u256_bit(b)))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheBitAnd => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a & b))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a & b))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a & b))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a & b))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a & b))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a & b))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a & b))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a & b))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a & b))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::FheBytes128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a & b))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::FheBytes256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a & b))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::FheBytes64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a & b))
+            }
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a & (to_be_u4_bit(b) > 0)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a & to_be_u4_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a & to_be_u8_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a & to_be_u16_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a & to_be_u32_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a & to_be_u64_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a & to_be_u128_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a & to_be_u160_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a & to_be_u256_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a & to_be_u512_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a & to_be_u1024_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a & to_be_u2048_bit(b)))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheBitOr => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a | b))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a | b))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a | b))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a | b))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a | b))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a | b))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a | b))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a | b))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a | b))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::FheBytes64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a | b))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::FheBytes128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a | b))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::FheBytes256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a | b))
+            }
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a | (to_be_u4_bit(b) > 0)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a | to_be_u4_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a | to_be_u8_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a | to_be_u16_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a | to_be_u32_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a | to_be_u64_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a | to_be_u128_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a | to_be_u160_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a | to_be_u256_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a | to_be_u512_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a | to_be_u1024_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a | to_be_u2048_bit(b)))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheBitXor => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::FheBytes64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::FheBytes128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::FheBytes256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a ^ b))
+            }
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a ^ (to_be_u4_bit(b) > 0)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a ^ to_be_u4_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a ^ to_be_u8_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a ^ to_be_u16_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a ^ to_be_u32_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a ^ to_be_u64_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a ^ to_be_u128_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a ^ to_be_u160_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a ^ to_be_u256_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a ^ to_be_u512_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a ^ to_be_u1024_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a ^ to_be_u2048_bit(b)))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheShl => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a << b))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a << b))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a << b))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a << b))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a << b))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a << b))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a << b))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a << b))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::FheBytes64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a << b))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::FheBytes128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a << b))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::FheBytes256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a << b))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a << to_be_u4_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a << to_be_u8_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a << to_be_u16_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a << to_be_u32_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a << to_be_u64_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a << to_be_u128_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a << to_be_u160_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a << to_be_u256_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a << to_be_u512_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes128(a << to_be_u1024_bit(b)),
+            ),
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes256(a << to_be_u2048_bit(b)),
+            ),
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheShr => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a >> b))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a >> b))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a >> b))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a >> b))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a >> b))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a >> b))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a >> b))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a >> b))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::FheBytes64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a >> b))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::FheBytes128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a >> b))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::FheBytes256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a >> b))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a >> to_be_u4_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a >> to_be_u8_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a >> to_be_u16_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a >> to_be_u32_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a >> to_be_u64_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a >> to_be_u128_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a >> to_be_u160_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a >> to_be_u256_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a >> to_be_u512_bit(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes128(a >> to_be_u1024_bit(b)),
+            ),
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes256(a >> to_be_u2048_bit(b)),
+            ),
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheRotl => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::FheBytes64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::FheBytes128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::FheBytes256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a.rotate_left(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint4(a.rotate_left(to_be_u8_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint8(a.rotate_left(to_be_u8_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint16(a.rotate_left(to_be_u16_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint32(a.rotate_left(to_be_u32_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint64(a.rotate_left(to_be_u64_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint128(a.rotate_left(to_be_u128_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint160(a.rotate_left(to_be_u160_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint256(a.rotate_left(to_be_u256_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes64(a.rotate_left(to_be_u512_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes128(a.rotate_left(to_be_u1024_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes256(a.rotate_left(to_be_u2048_bit(b))),
+            ),
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheRotr => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::FheBytes64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes64(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::FheBytes128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes128(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::FheBytes256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBytes256(a.rotate_right(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint4(a.rotate_right(to_be_u8_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint8(a.rotate_right(to_be_u8_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint16(a.rotate_right(to_be_u16_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint32(a.rotate_right(to_be_u32_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint64(a.rotate_right(to_be_u64_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint128(a.rotate_right(to_be_u128_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint160(a.rotate_right(to_be_u160_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint256(a.rotate_right(to_be_u256_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes64(a.rotate_right(to_be_u512_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes128(a.rotate_right(to_be_u1024_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheBytes256(a.rotate_right(to_be_u2048_bit(b))),
+            ),
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheMin => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a.min(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a.min(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a.min(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a.min(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a.min(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a.min(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a.min(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a.min(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a.min(to_be_u4_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a.min(to_be_u8_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a.min(to_be_u16_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a.min(to_be_u32_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a.min(to_be_u64_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint128(a.min(to_be_u128_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint160(a.min(to_be_u160_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint256(a.min(to_be_u256_bit(b))),
+            ),
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheMax => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a.max(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a.max(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a.max(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a.max(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a.max(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint128(a.max(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint160(a.max(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint256(a.max(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint4(a.max(to_be_u4_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint8(a.max(to_be_u8_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint16(a.max(to_be_u16_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint32(a.max(to_be_u32_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheUint64(a.max(to_be_u64_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint128(a.max(to_be_u128_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint160(a.max(to_be_u160_bit(b))),
+            ),
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
+                SupportedFheCiphertexts::FheUint256(a.max(to_be_u256_bit(b))),
+            ),
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheEq => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::FheBytes64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::FheBytes128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::FheBytes256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
+            }
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(arr_non_zero(b))))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u4_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u8_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u16_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u32_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u64_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u128_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u160_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u256_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u512_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u1024_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u2048_bit(b))))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheNe => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::FheBytes64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::FheBytes128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::FheBytes256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
+            }
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(arr_non_zero(b))))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u4_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u8_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u16_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u32_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u64_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u128_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u160_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u256_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u512_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u1024_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u2048_bit(b))))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheGe => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a | !b))
+            }
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a | !arr_non_zero(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u4_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u8_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u16_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u32_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u64_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u128_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u160_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u256_bit(b))))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheGt => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a & !b))
             }
-        }
-        SupportedFheOperations::FheBitXor => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a ^ b))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a ^ b))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a ^ b))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a ^ b))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a ^ b))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a ^ b))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a ^ b)),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a ^ b)),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a ^ b)),
-                (
-                    SupportedFheCiphertexts::FheBytes64(a),
-                    SupportedFheCiphertexts::FheBytes64(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes64(a ^ b)),
-                (
-                    SupportedFheCiphertexts::FheBytes128(a),
-                    SupportedFheCiphertexts::FheBytes128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes128(a ^ b)),
-                (
-                    SupportedFheCiphertexts::FheBytes256(a),
-                    SupportedFheCiphertexts::FheBytes256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes256(a ^ b)),
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a ^ (to_be_u4_bit(b) > 0)))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a ^ to_be_u4_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a ^ to_be_u8_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a ^ to_be_u16_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a ^ to_be_u32_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a ^ to_be_u64_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(a ^ to_be_u128_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(a ^ to_be_u160_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(a ^ to_be_u256_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes64(a ^ to_be_u512_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes128(a ^ to_be_u1024_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes256(a ^ to_be_u2048_bit(b)))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a & !arr_non_zero(b)))
             }
-        }
-        SupportedFheOperations::FheShl => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a << b))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a << b))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a << b))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a << b))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a << b))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a << b)),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a << b)),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a << b)),
-                (
-                    SupportedFheCiphertexts::FheBytes64(a),
-                    SupportedFheCiphertexts::FheBytes64(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes64(a << b)),
-                (
-                    SupportedFheCiphertexts::FheBytes128(a),
-                    SupportedFheCiphertexts::FheBytes128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes128(a << b)),
-                (
-                    SupportedFheCiphertexts::FheBytes256(a),
-                    SupportedFheCiphertexts::FheBytes256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes256(a << b)),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a << to_be_u4_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a << to_be_u8_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a << to_be_u16_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a << to_be_u32_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a << to_be_u64_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(a << to_be_u128_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(a << to_be_u160_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(a << to_be_u256_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes64(a << to_be_u512_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes128(
-                        a << to_be_u1024_bit(b),
-                    ))
-                }
-                (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes256(
-                        a << to_be_u2048_bit(b),
-                    ))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
             }
-        }
-        SupportedFheOperations::FheShr => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a >> b))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a >> b))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a >> b))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a >> b))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a >> b))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a >> b)),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a >> b)),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a >> b)),
-                (
-                    SupportedFheCiphertexts::FheBytes64(a),
-                    SupportedFheCiphertexts::FheBytes64(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes64(a >> b)),
-                (
-                    SupportedFheCiphertexts::FheBytes128(a),
-                    SupportedFheCiphertexts::FheBytes128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes128(a >> b)),
-                (
-                    SupportedFheCiphertexts::FheBytes256(a),
-                    SupportedFheCiphertexts::FheBytes256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes256(a >> b)),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a >> to_be_u4_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a >> to_be_u8_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a >> to_be_u16_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a >> to_be_u32_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a >> to_be_u64_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(a >> to_be_u128_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(a >> to_be_u160_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(a >> to_be_u256_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes64(a >> to_be_u512_bit(b)))
-                }
-                (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes128(
-                        a >> to_be_u1024_bit(b),
-                    ))
-                }
-                (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes256(
-                        a >> to_be_u2048_bit(b),
-                    ))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
             }
-        }
-        SupportedFheOperations::FheRotl => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a.rotate_left(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a.rotate_left(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a.rotate_left(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a.rotate_left(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a.rotate_left(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a.rotate_left(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a.rotate_left(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a.rotate_left(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes64(a),
-                    SupportedFheCiphertexts::FheBytes64(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes64(a.rotate_left(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes128(a),
-                    SupportedFheCiphertexts::FheBytes128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes128(a.rotate_left(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes256(a),
-                    SupportedFheCiphertexts::FheBytes256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes256(a.rotate_left(b))),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint4(a.rotate_left(to_be_u8_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint8(a.rotate_left(to_be_u8_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint16(a.rotate_left(to_be_u16_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint32(a.rotate_left(to_be_u32_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint64(a.rotate_left(to_be_u64_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint128(a.rotate_left(to_be_u128_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint160(a.rotate_left(to_be_u160_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint256(a.rotate_left(to_be_u256_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheBytes64(a.rotate_left(to_be_u512_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes128(
-                        a.rotate_left(to_be_u1024_bit(b)),
-                    ))
-                }
-                (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes256(
-                        a.rotate_left(to_be_u2048_bit(b)),
-                    ))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
             }
-        }
-        SupportedFheOperations::FheRotr => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a.rotate_right(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a.rotate_right(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a.rotate_right(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a.rotate_right(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a.rotate_right(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a.rotate_right(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a.rotate_right(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a.rotate_right(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes64(a),
-                    SupportedFheCiphertexts::FheBytes64(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes64(a.rotate_right(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes128(a),
-                    SupportedFheCiphertexts::FheBytes128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes128(a.rotate_right(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes256(a),
-                    SupportedFheCiphertexts::FheBytes256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBytes256(a.rotate_right(b))),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint4(a.rotate_right(to_be_u8_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint8(a.rotate_right(to_be_u8_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint16(a.rotate_right(to_be_u16_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint32(a.rotate_right(to_be_u32_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint64(a.rotate_right(to_be_u64_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint128(a.rotate_right(to_be_u128_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint160(a.rotate_right(to_be_u160_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint256(a.rotate_right(to_be_u256_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheBytes64(a.rotate_right(to_be_u512_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes128(
-                        a.rotate_right(to_be_u1024_bit(b)),
-                    ))
-                }
-                (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBytes256(
-                        a.rotate_right(to_be_u2048_bit(b)),
-                    ))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u4_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u8_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u16_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u32_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u64_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u128_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u160_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u256_bit(b))))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheLe => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(!a | b))
             }
-        }
-        SupportedFheOperations::FheMin => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a.min(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a.min(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a.min(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a.min(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a.min(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a.min(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a.min(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a.min(b))),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a.min(to_be_u4_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a.min(to_be_u8_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a.min(to_be_u16_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a.min(to_be_u32_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a.min(to_be_u64_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint128(a.min(to_be_u128_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint160(a.min(to_be_u160_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint256(a.min(to_be_u256_bit(b))),
-                ),
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(!a | arr_non_zero(b)))
             }
-        }
-        SupportedFheOperations::FheMax => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a.max(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a.max(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a.max(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a.max(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a.max(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint128(a.max(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint160(a.max(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheUint256(a.max(b))),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint4(a.max(to_be_u4_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint8(a.max(to_be_u8_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint16(a.max(to_be_u16_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint32(a.max(to_be_u32_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheUint64(a.max(to_be_u64_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint128(a.max(to_be_u128_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint160(a.max(to_be_u160_bit(b))),
-                ),
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => Ok(
-                    SupportedFheCiphertexts::FheUint256(a.max(to_be_u256_bit(b))),
-                ),
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
             }
-        }
-        SupportedFheOperations::FheEq => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.eq(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.eq(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.eq(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes64(a),
-                    SupportedFheCiphertexts::FheBytes64(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.eq(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes128(a),
-                    SupportedFheCiphertexts::FheBytes128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.eq(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes256(a),
-                    SupportedFheCiphertexts::FheBytes256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.eq(b))),
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(arr_non_zero(b))))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u4_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u8_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u16_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u32_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u64_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u128_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u160_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u256_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u512_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u1024_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.eq(to_be_u2048_bit(b))))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
             }
-        }
-        SupportedFheOperations::FheNe => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.ne(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.ne(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.ne(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes64(a),
-                    SupportedFheCiphertexts::FheBytes64(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.ne(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes128(a),
-                    SupportedFheCiphertexts::FheBytes128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.ne(b))),
-                (
-                    SupportedFheCiphertexts::FheBytes256(a),
-                    SupportedFheCiphertexts::FheBytes256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.ne(b))),
-                (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(arr_non_zero(b))))
-                }
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u4_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u8_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u16_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u32_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u64_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u128_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u160_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u256_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheBytes64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u512_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheBytes128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u1024_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheBytes256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ne(to_be_u2048_bit(b))))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
             }
-        }
-        SupportedFheOperations::FheGe => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.ge(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.ge(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.ge(b))),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u4_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u8_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u16_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u32_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u64_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u128_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u160_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.ge(to_be_u256_bit(b))))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u4_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u8_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u16_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u32_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u64_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u128_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u160_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u256_bit(b))))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheLt => match (&input_operands[0], &input_operands[1]) {
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::FheBool(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(!a & b))
+            }
+            (SupportedFheCiphertexts::FheBool(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(!a & arr_non_zero(b)))
+            }
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::FheUint128(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::FheUint160(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::FheUint256(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
             }
-        }
-        SupportedFheOperations::FheGt => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.gt(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.gt(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.gt(b))),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u4_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u8_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u16_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u32_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u64_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u128_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u160_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.gt(to_be_u256_bit(b))))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u4_bit(b))))
             }
-        }
-        SupportedFheOperations::FheLe => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.le(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.le(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.le(b))),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u4_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u8_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u16_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u32_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u64_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u128_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u160_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.le(to_be_u256_bit(b))))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u8_bit(b))))
             }
-        }
-        SupportedFheOperations::FheLt => {
-            assert_eq!(input_operands.len(), 2);
-
-            match (&input_operands[0], &input_operands[1]) {
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::FheUint4(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::FheUint8(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::FheUint16(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::FheUint32(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::FheUint64(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(b)))
-                }
-                (
-                    SupportedFheCiphertexts::FheUint128(a),
-                    SupportedFheCiphertexts::FheUint128(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.lt(b))),
-                (
-                    SupportedFheCiphertexts::FheUint160(a),
-                    SupportedFheCiphertexts::FheUint160(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.lt(b))),
-                (
-                    SupportedFheCiphertexts::FheUint256(a),
-                    SupportedFheCiphertexts::FheUint256(b),
-                ) => Ok(SupportedFheCiphertexts::FheBool(a.lt(b))),
-                (SupportedFheCiphertexts::FheUint4(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u4_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint8(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u8_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u16_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u32_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u64_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u128_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u160_bit(b))))
-                }
-                (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
-                    Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u256_bit(b))))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint16(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u16_bit(b))))
             }
-        }
-        SupportedFheOperations::FheNot => {
-            assert_eq!(input_operands.len(), 1);
-
-            match &input_operands[0] {
-                SupportedFheCiphertexts::FheBool(a) => Ok(SupportedFheCiphertexts::FheBool(!a)),
-                SupportedFheCiphertexts::FheUint4(a) => Ok(SupportedFheCiphertexts::FheUint4(!a)),
-                SupportedFheCiphertexts::FheUint8(a) => Ok(SupportedFheCiphertexts::FheUint8(!a)),
-                SupportedFheCiphertexts::FheUint16(a) => Ok(SupportedFheCiphertexts::FheUint16(!a)),
-                SupportedFheCiphertexts::FheUint32(a) => Ok(SupportedFheCiphertexts::FheUint32(!a)),
-                SupportedFheCiphertexts::FheUint64(a) => Ok(SupportedFheCiphertexts::FheUint64(!a)),
-                SupportedFheCiphertexts::FheUint128(a) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(!a))
-                }
-                SupportedFheCiphertexts::FheUint160(a) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(!a))
-                }
-                SupportedFheCiphertexts::FheUint256(a) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(!a))
-                }
-                SupportedFheCiphertexts::FheBytes64(a) => {
-                    Ok(SupportedFheCiphertexts::FheBytes64(!a))
-                }
-                SupportedFheCiphertexts::FheBytes128(a) => {
-                    Ok(SupportedFheCiphertexts::FheBytes128(!a))
-                }
-                SupportedFheCiphertexts::FheBytes256(a) => {
-                    Ok(SupportedFheCiphertexts::FheBytes256(!a))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint32(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u32_bit(b))))
             }
-        }
-        SupportedFheOperations::FheNeg => {
-            assert_eq!(input_operands.len(), 1);
-
-            match &input_operands[0] {
-                SupportedFheCiphertexts::FheUint4(a) => Ok(SupportedFheCiphertexts::FheUint4(-a)),
-                SupportedFheCiphertexts::FheUint8(a) => Ok(SupportedFheCiphertexts::FheUint8(-a)),
-                SupportedFheCiphertexts::FheUint16(a) => Ok(SupportedFheCiphertexts::FheUint16(-a)),
-                SupportedFheCiphertexts::FheUint32(a) => Ok(SupportedFheCiphertexts::FheUint32(-a)),
-                SupportedFheCiphertexts::FheUint64(a) => Ok(SupportedFheCiphertexts::FheUint64(-a)),
-                SupportedFheCiphertexts::FheUint128(a) => {
-                    Ok(SupportedFheCiphertexts::FheUint128(-a))
-                }
-                SupportedFheCiphertexts::FheUint160(a) => {
-                    Ok(SupportedFheCiphertexts::FheUint160(-a))
-                }
-                SupportedFheCiphertexts::FheUint256(a) => {
-                    Ok(SupportedFheCiphertexts::FheUint256(-a))
-                }
-                _ => Err(FhevmError::UnsupportedFheTypes {
-                    fhe_operation: format!("{:?}", fhe_operation),
-                    input_types: input_operands.iter().map(|i| i.type_name()).collect(),
-                }),
+            (SupportedFheCiphertexts::FheUint64(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u64_bit(b))))
             }
-        }
+            (SupportedFheCiphertexts::FheUint128(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u128_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint160(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u160_bit(b))))
+            }
+            (SupportedFheCiphertexts::FheUint256(a), SupportedFheCiphertexts::Scalar(b)) => {
+                Ok(SupportedFheCiphertexts::FheBool(a.lt(to_be_u256_bit(b))))
+            }
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheNot => match &input_operands[0] {
+            SupportedFheCiphertexts::FheBool(a) => Ok(SupportedFheCiphertexts::FheBool(!a)),
+            SupportedFheCiphertexts::FheUint4(a) => Ok(SupportedFheCiphertexts::FheUint4(!a)),
+            SupportedFheCiphertexts::FheUint8(a) => Ok(SupportedFheCiphertexts::FheUint8(!a)),
+            SupportedFheCiphertexts::FheUint16(a) => Ok(SupportedFheCiphertexts::FheUint16(!a)),
+            SupportedFheCiphertexts::FheUint32(a) => Ok(SupportedFheCiphertexts::FheUint32(!a)),
+            SupportedFheCiphertexts::FheUint64(a) => Ok(SupportedFheCiphertexts::FheUint64(!a)),
+            SupportedFheCiphertexts::FheUint128(a) => Ok(SupportedFheCiphertexts::FheUint128(!a)),
+            SupportedFheCiphertexts::FheUint160(a) => Ok(SupportedFheCiphertexts::FheUint160(!a)),
+            SupportedFheCiphertexts::FheUint256(a) => Ok(SupportedFheCiphertexts::FheUint256(!a)),
+            SupportedFheCiphertexts::FheBytes64(a) => Ok(SupportedFheCiphertexts::FheBytes64(!a)),
+            SupportedFheCiphertexts::FheBytes128(a) => Ok(SupportedFheCiphertexts::FheBytes128(!a)),
+            SupportedFheCiphertexts::FheBytes256(a) => Ok(SupportedFheCiphertexts::FheBytes256(!a)),
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
+        SupportedFheOperations::FheNeg => match &input_operands[0] {
+            SupportedFheCiphertexts::FheUint4(a) => Ok(SupportedFheCiphertexts::FheUint4(-a)),
+            SupportedFheCiphertexts::FheUint8(a) => Ok(SupportedFheCiphertexts::FheUint8(-a)),
+            SupportedFheCiphertexts::FheUint16(a) => Ok(SupportedFheCiphertexts::FheUint16(-a)),
+            SupportedFheCiphertexts::FheUint32(a) => Ok(SupportedFheCiphertexts::FheUint32(-a)),
+            SupportedFheCiphertexts::FheUint64(a) => Ok(SupportedFheCiphertexts::FheUint64(-a)),
+            SupportedFheCiphertexts::FheUint128(a) => Ok(SupportedFheCiphertexts::FheUint128(-a)),
+            SupportedFheCiphertexts::FheUint160(a) => Ok(SupportedFheCiphertexts::FheUint160(-a)),
+            SupportedFheCiphertexts::FheUint256(a) => Ok(SupportedFheCiphertexts::FheUint256(-a)),
+            _ => Err(FhevmError::UnsupportedFheTypes {
+                fhe_operation: format!("{:?}", fhe_operation),
+                input_types: input_operands.iter().map(|i| i.type_name()).collect(),
+            }),
+        },
         SupportedFheOperations::FheIfThenElse => {
-            assert_eq!(input_operands.len(), 3);
-
             let SupportedFheCiphertexts::FheBool(flag) = &input_operands[0] else {
                 return Err(FhevmError::UnsupportedFheTypes {
                     fhe_operation: format!("{:?}", fhe_operation),
@@ -3028,7 +2914,10 @@ pub fn perform_fhe_operation(
                             let out: tfhe::FheUint1024 = inp.clone().cast_into();
                             Ok(SupportedFheCiphertexts::FheBytes128(out))
                         }
-                        other => panic!("unexpected type: {other}"),
+                        other => Err(FhevmError::UnknownCastType {
+                            fhe_operation: format!("{:?}", fhe_operation),
+                            type_to_cast_to: other,
+                        }),
                     }
                 }
             }
@@ -3040,7 +2929,10 @@ pub fn perform_fhe_operation(
         SupportedFheOperations::FheTrivialEncrypt => match (&input_operands[0], &input_operands[1])
         {
             (SupportedFheCiphertexts::Scalar(inp), SupportedFheCiphertexts::Scalar(op)) => {
-                Ok(trivial_encrypt_be_bytes(to_be_u16_bit(op) as i16, inp))
+                let to_type = to_be_u16_bit(op) as i16;
+                // trivial_encrypt_be_bytes panics on unknown types
+                validate_fhe_type(to_type as i32)?;
+                Ok(trivial_encrypt_be_bytes(to_type, inp))
             }
             _ => Err(FhevmError::UnsupportedFheTypes {
                 fhe_operation: format!("{:?}", fhe_operation),
@@ -3062,6 +2954,8 @@ pub fn perform_fhe_operation(
             };
             let rand_seed = to_be_u128_bit(rand_counter);
             let to_type = to_be_u16_bit(to_type) as i16;
+            // generate_random_number panics on unknown types
+            validate_fhe_type(to_type as i32)?;
             Ok(generate_random_number(to_type as i16, rand_seed, None))
         }
         SupportedFheOperations::FheRandBounded => {
@@ -3085,6 +2979,8 @@ pub fn perform_fhe_operation(
             };
             let rand_seed = to_be_u128_bit(rand_counter);
             let to_type = to_be_u16_bit(to_type) as i16;
+            // generate_random_number panics on unknown types
+            validate_fhe_type(to_type as i32)?;
             Ok(generate_random_number(
                 to_type as i16,
                 rand_seed,
@@ -3095,6 +2991,11 @@ pub fn perform_fhe_operation(
     }
 }
 
+// Scalar operands are decoded as big endian numbers. Inputs wider than
+// the target type keep only their low order bytes, which reduces the value
+// modulo 2^N like a wrapping cast, so oversized scalars never overflow.
+// Scalars arrive as the full 32 byte word of the host contract event
+// whatever the operand type, so this is the normal path, not an error.
 pub fn to_be_u4_bit(inp: &[u8]) -> u8 {
     inp.last().unwrap_or(&0) & 0x0f
 }
@@ -3112,7 +3013,7 @@ fn to_constant_size_array<const SIZE: usize>(inp: &[u8]) -> [u8; SIZE] {
 
     match inp.len().cmp(&SIZE) {
         std::cmp::Ordering::Less => {
-            // truncate input slice from the left
+            // input slice smaller than result, pad with zeros from the left
             let slice = &mut res[SIZE - inp.len()..];
             slice.copy_from_slice(inp);
         }
@@ -3224,6 +3125,536 @@ fn be_number_random_bits(inp: &[u8]) -> u32 {
     res
 }
 
+#[test]
+fn short_operand_slices_are_rejected() {
+    for (op, operands) in [
+        (SupportedFheOperations::FheCast, vec![]),
+        (SupportedFheOperations::FheRand, vec![]),
+        (SupportedFheOperations::FheRandBounded, vec![]),
+        (
+            SupportedFheOperations::FheRandBounded,
+            vec![SupportedFheCiphertexts::Scalar(vec![1])],
+        ),
+    ] {
+        let res = perform_fhe_operation(op as i16, &operands);
+        assert!(
+            matches!(
+                res,
+                Err(FhevmError::UnexpectedOperandCountForFheOperation { got_operands, .. })
+                    if got_operands == operands.len()
+            ),
+            "{op:?} with {} operands",
+            operands.len()
+        );
+    }
+}
+
+#[test]
+fn unary_ops_reject_extra_operands() {
+    let operands = [
+        SupportedFheCiphertexts::Scalar(vec![1]),
+        SupportedFheCiphertexts::Scalar(vec![2]),
+    ];
+    for op in [
+        SupportedFheOperations::FheNot,
+        SupportedFheOperations::FheNeg,
+    ] {
+        let res = perform_fhe_operation(op as i16, &operands);
+        assert!(
+            matches!(
+                res,
+                Err(FhevmError::UnexpectedOperandCountForFheOperation {
+                    expected_operands: 1,
+                    got_operands: 2,
+                    ..
+                })
+            ),
+            "{op:?} with 2 operands"
+        );
+
+        let res = check_fhe_operand_types(op as i32, &[vec![0; 32], vec![0; 32]], &[false, false]);
+        assert!(
+            matches!(
+                res,
+                Err(FhevmError::UnexpectedOperandCountForFheOperation {
+                    expected_operands: 1,
+                    got_operands: 2,
+                    ..
+                })
+            ),
+            "{op:?} handles with 2 operands"
+        );
+    }
+}
+
+#[test]
+fn unary_ops_reject_scalar_operand() {
+    for op in [
+        SupportedFheOperations::FheNot,
+        SupportedFheOperations::FheNeg,
+    ] {
+        let res = perform_fhe_operation(op as i16, &[SupportedFheCiphertexts::Scalar(vec![1])]);
+        assert!(
+            matches!(res, Err(FhevmError::UnsupportedFheTypes { .. })),
+            "{op:?} on a scalar"
+        );
+    }
+}
+
+#[test]
+fn trivial_encrypt_rejects_unknown_type() {
+    for to_type in [12u8, 200, 255] {
+        let res = perform_fhe_operation(
+            SupportedFheOperations::FheTrivialEncrypt as i16,
+            &[
+                SupportedFheCiphertexts::Scalar(vec![1]),
+                SupportedFheCiphertexts::Scalar(vec![to_type]),
+            ],
+        );
+        assert!(
+            matches!(res, Err(FhevmError::UnknownFheType(t)) if t == to_type as i32),
+            "trivial encrypt to type {to_type}"
+        );
+
+        let res = check_fhe_operand_types(
+            SupportedFheOperations::FheTrivialEncrypt as i32,
+            &[vec![1], vec![to_type]],
+            &[true, true],
+        );
+        assert!(matches!(res, Err(FhevmError::UnknownFheType(_))));
+    }
+}
+
+#[test]
+fn cast_type_operand_position() {
+    use tfhe::prelude::FheEncrypt;
+
+    let cast = SupportedFheOperations::FheCast;
+    let handle = vec![0; 32];
+    let to_type = vec![2];
+    assert!(check_fhe_operand_types(
+        cast as i32,
+        &[handle.clone(), to_type.clone()],
+        &[false, true]
+    )
+    .is_ok());
+    // the worker only treats the second operand as scalar
+    assert!(matches!(
+        check_fhe_operand_types(cast as i32, &[to_type, handle], &[true, false]),
+        Err(FhevmError::FheOperationOnlySecondOperandCanBeScalar {
+            scalar_input_index: 0,
+            ..
+        })
+    ));
+    assert!(is_supported(cast as i16, &[2, 200]));
+    assert!(!is_supported(cast as i16, &[200, 2]));
+    assert!(!is_supported(cast as i16, &[200, 200]));
+
+    // casting to the same type needs no server key
+    let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+    let ct = SupportedFheCiphertexts::FheUint8(FheUint8::encrypt(42u8, &client_key));
+    let to_type = SupportedFheCiphertexts::Scalar(vec![2]);
+    let res = perform_fhe_operation(cast as i16, &[ct.clone(), to_type.clone()]).unwrap();
+    assert_eq!(res.type_num(), 2);
+    assert_eq!(res.decrypt(&client_key), "42");
+    assert!(matches!(
+        perform_fhe_operation(cast as i16, &[to_type, ct]),
+        Err(FhevmError::UnsupportedFheTypes { .. })
+    ));
+}
+
+#[test]
+fn cast_to_unknown_type_is_an_error() {
+    use tfhe::prelude::FheEncrypt;
+
+    let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+    let sources = [
+        SupportedFheCiphertexts::FheBool(FheBool::encrypt(true, &client_key)),
+        SupportedFheCiphertexts::FheUint64(FheUint64::encrypt(1u64, &client_key)),
+        SupportedFheCiphertexts::FheBytes256(FheUint2048::encrypt(
+            StaticUnsignedBigInt::<32>::ONE,
+            &client_key,
+        )),
+    ];
+    for source in sources {
+        let res = perform_fhe_operation(
+            SupportedFheOperations::FheCast as i16,
+            &[source.clone(), SupportedFheCiphertexts::Scalar(vec![12])],
+        );
+        assert!(
+            matches!(
+                res,
+                Err(FhevmError::UnknownCastType {
+                    type_to_cast_to: 12,
+                    ..
+                })
+            ),
+            "cast from {}",
+            source.type_name()
+        );
+    }
+}
+
+#[test]
+fn disabled_operations_are_rejected() {
+    let disabled = "FheDiv,FheRem"
+        .split(',')
+        .map(|op| op.parse::<SupportedFheOperations>().unwrap())
+        .collect::<Vec<_>>();
+    let div = SupportedFheOperations::FheDiv as i32;
+    assert!(matches!(
+        check_fhe_operation_enabled(div, &disabled),
+        Err(FhevmError::FheOperationDisabled { fhe_operation, .. }) if fhe_operation == div
+    ));
+    assert!(check_fhe_operation_enabled(SupportedFheOperations::FheAdd as i32, &disabled).is_ok());
+    // nothing is disabled by default
+    assert!(check_fhe_operation_enabled(div, &[]).is_ok());
+    assert!(matches!(
+        check_fhe_operation_enabled(1000, &disabled),
+        Err(FhevmError::UnknownFheOperation(1000))
+    ));
+}
+
+#[test]
+fn supported_operand_types() {
+    let op = |op: SupportedFheOperations| op as i16;
+    let scalar = 200;
+    assert!(is_supported(op(SupportedFheOperations::FheAdd), &[4, 4]));
+    assert!(is_supported(
+        op(SupportedFheOperations::FheAdd),
+        &[4, scalar]
+    ));
+    assert!(!is_supported(op(SupportedFheOperations::FheAdd), &[4, 5]));
+    assert!(!is_supported(
+        op(SupportedFheOperations::FheAdd),
+        &[scalar, 4]
+    ));
+    assert!(!is_supported(op(SupportedFheOperations::FheAdd), &[9, 9]));
+    assert!(!is_supported(op(SupportedFheOperations::FheAdd), &[4]));
+    assert!(is_supported(op(SupportedFheOperations::FheBitAnd), &[0, 0]));
+    assert!(is_supported(
+        op(SupportedFheOperations::FheEq),
+        &[11, scalar]
+    ));
+    assert!(!is_supported(op(SupportedFheOperations::FheShl), &[0, 0]));
+    assert!(is_supported(op(SupportedFheOperations::FheNot), &[0]));
+    assert!(!is_supported(op(SupportedFheOperations::FheNeg), &[0]));
+    assert!(!is_supported(op(SupportedFheOperations::FheNeg), &[10]));
+    assert!(!is_supported(op(SupportedFheOperations::FheNeg), &[scalar]));
+    assert!(is_supported(
+        op(SupportedFheOperations::FheIfThenElse),
+        &[0, 11, 11]
+    ));
+    assert!(!is_supported(
+        op(SupportedFheOperations::FheIfThenElse),
+        &[1, 4, 4]
+    ));
+    assert!(!is_supported(
+        op(SupportedFheOperations::FheIfThenElse),
+        &[0, 4, 5]
+    ));
+    assert!(is_supported(
+        op(SupportedFheOperations::FheCast),
+        &[3, scalar]
+    ));
+    assert!(is_supported(
+        op(SupportedFheOperations::FheRandBounded),
+        &[scalar; 3]
+    ));
+    assert!(!is_supported(
+        op(SupportedFheOperations::FheTrivialEncrypt),
+        &[scalar, 4]
+    ));
+    assert!(!is_supported(
+        op(SupportedFheOperations::FheGetInputCiphertext),
+        &[scalar]
+    ));
+    assert!(!is_supported(-1, &[4, 4]));
+}
+
+#[test]
+fn output_types() {
+    let op = |op: SupportedFheOperations| op as i16;
+    assert_eq!(
+        output_type_of(op(SupportedFheOperations::FheMax), &[5, 5]),
+        Some(5)
+    );
+    assert_eq!(
+        output_type_of(op(SupportedFheOperations::FheMin), &[3, 200]),
+        Some(3)
+    );
+    assert_eq!(
+        output_type_of(op(SupportedFheOperations::FheLt), &[5, 5]),
+        Some(0)
+    );
+    assert_eq!(
+        output_type_of(op(SupportedFheOperations::FheEq), &[11, 11]),
+        Some(0)
+    );
+    assert_eq!(
+        output_type_of(op(SupportedFheOperations::FheNot), &[9]),
+        Some(9)
+    );
+    assert_eq!(
+        output_type_of(op(SupportedFheOperations::FheCast), &[3, 200]),
+        None
+    );
+    assert_eq!(
+        output_type_of(op(SupportedFheOperations::FheMax), &[5, 4]),
+        None
+    );
+}
+
+#[test]
+fn latency_weights() {
+    let weight =
+        |op: SupportedFheOperations, tags: &[i16]| estimate_op_latency_weight(op as i16, tags);
+    for tag in 1..=8 {
+        let add = weight(SupportedFheOperations::FheAdd, &[tag, tag]);
+        let lt = weight(SupportedFheOperations::FheLt, &[tag, tag]);
+        let mul = weight(SupportedFheOperations::FheMul, &[tag, tag]);
+        let div = weight(SupportedFheOperations::FheDiv, &[tag, 200]);
+        assert!(add < lt && lt < mul && mul < div, "type {tag}");
+    }
+    assert!(
+        weight(SupportedFheOperations::FheAdd, &[5, 5])
+            > weight(SupportedFheOperations::FheAdd, &[2, 2])
+    );
+    assert_eq!(estimate_op_latency_weight(-1, &[5, 5]), 0);
+}
+
+#[test]
+fn rand_rejects_unknown_type() {
+    let scalar = |v: u8| SupportedFheCiphertexts::Scalar(vec![v]);
+    for to_type in [12u8, 200] {
+        let res = perform_fhe_operation(
+            SupportedFheOperations::FheRand as i16,
+            &[scalar(1), scalar(to_type)],
+        );
+        assert!(matches!(res, Err(FhevmError::UnknownFheType(t)) if t == to_type as i32));
+
+        let res = perform_fhe_operation(
+            SupportedFheOperations::FheRandBounded as i16,
+            &[scalar(1), scalar(16), scalar(to_type)],
+        );
+        assert!(matches!(res, Err(FhevmError::UnknownFheType(t)) if t == to_type as i32));
+    }
+}
+
+#[test]
+fn arithmetic_rejects_ebytes() {
+    use tfhe::prelude::FheEncrypt;
+
+    let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+    let ct = SupportedFheCiphertexts::FheBytes256(FheUint2048::encrypt(
+        StaticUnsignedBigInt::<32>::from(1u8),
+        &client_key,
+    ));
+    for op in [
+        SupportedFheOperations::FheAdd,
+        SupportedFheOperations::FheSub,
+        SupportedFheOperations::FheMul,
+        SupportedFheOperations::FheDiv,
+        SupportedFheOperations::FheRem,
+    ] {
+        assert!(!op.supports_ebytes_inputs());
+        for rhs in [ct.clone(), SupportedFheCiphertexts::Scalar(vec![1])] {
+            let res = perform_fhe_operation(op as i16, &[ct.clone(), rhs]);
+            assert!(
+                matches!(res, Err(FhevmError::UnsupportedFheTypes { .. })),
+                "{op:?} on FheBytes256"
+            );
+        }
+    }
+}
+
+/// Keys shared by the tests executing FHE operations, a server key being
+/// slow to generate
+#[cfg(test)]
+fn test_keys() -> &'static (tfhe::ClientKey, tfhe::ServerKey) {
+    static KEYS: std::sync::OnceLock<(tfhe::ClientKey, tfhe::ServerKey)> =
+        std::sync::OnceLock::new();
+    KEYS.get_or_init(|| {
+        let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+        let server_key = tfhe::ServerKey::new(&client_key);
+        (client_key, server_key)
+    })
+}
+
+#[test]
+fn bool_ordered_comparisons_truth_table() {
+    use tfhe::prelude::FheEncrypt;
+
+    let (client_key, server_key) = test_keys();
+    tfhe::set_server_key(server_key.clone());
+    for op in [
+        SupportedFheOperations::FheGe,
+        SupportedFheOperations::FheGt,
+        SupportedFheOperations::FheLe,
+        SupportedFheOperations::FheLt,
+    ] {
+        for a in [false, true] {
+            let lhs = SupportedFheCiphertexts::FheBool(FheBool::encrypt(a, client_key));
+            for b in [false, true] {
+                let expected = match op {
+                    SupportedFheOperations::FheGe => a >= b,
+                    SupportedFheOperations::FheGt => a & !b,
+                    SupportedFheOperations::FheLe => a <= b,
+                    _ => !a & b,
+                };
+                let encrypted = SupportedFheCiphertexts::FheBool(FheBool::encrypt(b, client_key));
+                let scalar = SupportedFheCiphertexts::Scalar(vec![b as u8]);
+                for rhs in [encrypted, scalar] {
+                    let rhs_type = rhs.type_name();
+                    let res = perform_fhe_operation(op as i16, &[lhs.clone(), rhs]).unwrap();
+                    assert_eq!(
+                        res.decrypt(client_key),
+                        expected.to_string(),
+                        "{op:?}({a}, {b} as {rhs_type})"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn bool_scalar_comparisons_match_equality() {
+    use tfhe::prelude::FheEncrypt;
+
+    let scalar = 200;
+    for op in [
+        SupportedFheOperations::FheEq,
+        SupportedFheOperations::FheNe,
+        SupportedFheOperations::FheGe,
+        SupportedFheOperations::FheGt,
+        SupportedFheOperations::FheLe,
+        SupportedFheOperations::FheLt,
+    ] {
+        assert!(is_supported(op as i16, &[0, scalar]), "{op:?}");
+        assert!(is_supported(op as i16, &[0, 0]), "{op:?}");
+        assert_eq!(output_type_of(op as i16, &[0, scalar]), Some(0), "{op:?}");
+    }
+    // ebytes have no ordering
+    assert!(is_supported(
+        SupportedFheOperations::FheEq as i16,
+        &[9, scalar]
+    ));
+    assert!(!is_supported(
+        SupportedFheOperations::FheGt as i16,
+        &[9, scalar]
+    ));
+
+    // both execute to bools of the same size
+    let (client_key, server_key) = test_keys();
+    tfhe::set_server_key(server_key.clone());
+    for a in [false, true] {
+        let lhs = SupportedFheCiphertexts::FheBool(FheBool::encrypt(a, client_key));
+        for b in [false, true] {
+            let operands = [lhs.clone(), SupportedFheCiphertexts::Scalar(vec![b as u8])];
+            let gt =
+                perform_fhe_operation(SupportedFheOperations::FheGt as i16, &operands).unwrap();
+            let eq =
+                perform_fhe_operation(SupportedFheOperations::FheEq as i16, &operands).unwrap();
+            assert_eq!(gt.type_num(), 0);
+            assert_eq!(eq.type_num(), 0);
+            assert_eq!(gt.serialize().1.len(), eq.serialize().1.len());
+            assert_eq!(gt.decrypt(client_key), (a & !b).to_string(), "{a} > {b}");
+            assert_eq!(eq.decrypt(client_key), (a == b).to_string(), "{a} == {b}");
+        }
+    }
+}
+
+#[test]
+fn mul_keeps_operand_width() {
+    let mul = SupportedFheOperations::FheMul as i16;
+    for tag in 1..=8 {
+        assert_eq!(output_type_of(mul, &[tag, tag]), Some(tag));
+        assert_eq!(output_type_of(mul, &[tag, 200]), Some(tag));
+    }
+}
+
+#[test]
+fn if_then_else_output_type() {
+    let op = SupportedFheOperations::FheIfThenElse as i16;
+    for branch_type in [0, 4, 9, 10, 11] {
+        assert_eq!(
+            output_type_of(op, &[0, branch_type, branch_type]),
+            Some(branch_type)
+        );
+    }
+    assert_eq!(output_type_of(op, &[0, 9, 11]), None);
+}
+
+#[test]
+fn unsupported_operand_types_never_panic() {
+    use strum::IntoEnumIterator;
+    use tfhe::prelude::FheEncrypt;
+
+    let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+    let ck = &client_key;
+    let encrypt = |tag: i16| match tag {
+        0 => SupportedFheCiphertexts::FheBool(FheBool::encrypt(true, ck)),
+        1 => SupportedFheCiphertexts::FheUint4(FheUint4::encrypt(1u8, ck)),
+        2 => SupportedFheCiphertexts::FheUint8(FheUint8::encrypt(1u8, ck)),
+        3 => SupportedFheCiphertexts::FheUint16(FheUint16::encrypt(1u16, ck)),
+        4 => SupportedFheCiphertexts::FheUint32(FheUint32::encrypt(1u32, ck)),
+        5 => SupportedFheCiphertexts::FheUint64(FheUint64::encrypt(1u64, ck)),
+        6 => SupportedFheCiphertexts::FheUint128(FheUint128::encrypt(1u128, ck)),
+        7 => SupportedFheCiphertexts::FheUint160(FheUint160::encrypt(U256::ONE, ck)),
+        8 => SupportedFheCiphertexts::FheUint256(FheUint256::encrypt(U256::ONE, ck)),
+        9 => SupportedFheCiphertexts::FheBytes64(FheUint512::encrypt(
+            StaticUnsignedBigInt::<8>::ONE,
+            ck,
+        )),
+        10 => SupportedFheCiphertexts::FheBytes128(FheUint1024::encrypt(
+            StaticUnsignedBigInt::<16>::ONE,
+            ck,
+        )),
+        _ => SupportedFheCiphertexts::FheBytes256(FheUint2048::encrypt(
+            StaticUnsignedBigInt::<32>::ONE,
+            ck,
+        )),
+    };
+    let cts = (0..12).map(encrypt).collect::<Vec<_>>();
+    let scalar = SupportedFheCiphertexts::Scalar(vec![1]);
+    let flag = &cts[0];
+    let mut gaps = Vec::new();
+    for op in SupportedFheOperations::iter() {
+        // input ciphertexts are fetched, never computed
+        if op == SupportedFheOperations::FheGetInputCiphertext {
+            continue;
+        }
+        for ct in &cts {
+            for operands in [
+                vec![ct.clone()],
+                vec![scalar.clone()],
+                vec![ct.clone(), ct.clone()],
+                vec![ct.clone(), scalar.clone()],
+                vec![scalar.clone(), ct.clone()],
+                vec![ct.clone(), ct.clone(), ct.clone()],
+                vec![flag.clone(), ct.clone(), scalar.clone()],
+            ] {
+                let tags = operands.iter().map(|o| o.type_num()).collect::<Vec<_>>();
+                if is_supported(op as i16, &tags) {
+                    continue;
+                }
+                let res = std::panic::catch_unwind(|| perform_fhe_operation(op as i16, &operands));
+                match res {
+                    Ok(Err(_)) => {}
+                    Ok(Ok(_)) => gaps.push(format!("{op:?}{tags:?} unexpectedly succeeded")),
+                    Err(_) => gaps.push(format!("{op:?}{tags:?} panicked")),
+                }
+            }
+        }
+    }
+    assert!(
+        gaps.is_empty(),
+        "unsupported combinations:\n{}",
+        gaps.join("\n")
+    );
+}
+
 #[test]
 fn random_bits_from_arr() {
     assert_eq!(be_number_random_bits(&(1u32).to_be_bytes()), 0);
@@ -3245,6 +3676,45 @@ fn random_bits_from_arr() {
     assert_eq!(be_number_random_bits(&(65536u32).to_be_bytes()), 16);
 }
 
+#[test]
+fn to_be_conversions_round_trip() {
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    for _ in 0..1000 {
+        let v: u128 = rng.random();
+        let bytes = v.to_be_bytes();
+        assert_eq!(to_be_u128_bit(&bytes), v);
+        assert_eq!(to_be_u64_bit(&(v as u64).to_be_bytes()), v as u64);
+        assert_eq!(to_be_u32_bit(&(v as u32).to_be_bytes()), v as u32);
+        assert_eq!(to_be_u16_bit(&(v as u16).to_be_bytes()), v as u16);
+        assert_eq!(to_be_u256_bit(&bytes), U256::from(v));
+        // wider inputs keep the low order bytes
+        assert_eq!(to_be_u64_bit(&bytes), v as u64);
+        assert_eq!(to_be_u32_bit(&bytes), v as u32);
+        assert_eq!(to_be_u16_bit(&bytes), v as u16);
+        assert_eq!(to_be_u8_bit(&bytes), v as u8);
+        assert_eq!(to_be_u4_bit(&bytes), v as u8 & 0x0f);
+        // narrower inputs are zero extended
+        assert_eq!(to_be_u128_bit(&(v as u32).to_be_bytes()), v as u32 as u128);
+        assert_eq!(to_be_u160_bit(&bytes), U256::from(v));
+    }
+}
+
+#[test]
+fn oversized_scalars_are_truncated_from_the_left() {
+    let all_ones = [0xffu8; 33];
+    assert_eq!(to_be_u256_bit(&all_ones), U256::MAX);
+    assert_eq!(to_be_u160_bit(&all_ones), (U256::ONE << 160u32) - U256::ONE);
+    assert_eq!(to_be_u128_bit(&all_ones), u128::MAX);
+
+    let mut high_byte_only = [0u8; 33];
+    high_byte_only[0] = 0xff;
+    assert_eq!(to_be_u256_bit(&high_byte_only), U256::ZERO);
+    assert_eq!(to_be_u8_bit(&[]), 0);
+    assert_eq!(to_be_u256_bit(&[]), U256::ZERO);
+}
+
 pub fn generate_random_number(
     the_type: i16,
     seed: u128,
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use anyhow::Result;
 use bigdecimal::num_bigint::BigInt;
 use tfhe::integer::bigint::StaticUnsignedBigInt;
@@ -7,7 +9,7 @@ use tfhe::prelude::{CiphertextList, FheDecrypt};
 use tfhe::shortint::Ciphertext;
 use tfhe::{CompressedCiphertextList, CompressedCiphertextListBuilder};
 
-use crate::utils::{safe_deserialize, safe_serialize};
+use crate::utils::{safe_deserialize, safe_serialize, safe_serialized_size};
 
 #[derive(Debug)]
 pub enum FhevmError {
@@ -123,6 +125,11 @@ pub enum FhevmError {
         fhe_operation: String,
         type_to_cast_to: i16,
     },
+    FheOperationDisabled {
+        fhe_operation: i32,
+        fhe_operation_name: String,
+    },
+    NoGpusAvailable,
 }
 
 impl std::error::Error for FhevmError {}
@@ -307,6 +314,21 @@ impl std::fmt::Display for FhevmError {
                     type_to_cast_to
                 )
             }
+            Self::FheOperationDisabled {
+                fhe_operation,
+                fhe_operation_name,
+            } => {
+                write!(
+                    f,
+                    "fhe operation number {fhe_operation} ({fhe_operation_name}) is disabled"
+                )
+            }
+            Self::NoGpusAvailable => {
+                write!(
+                    f,
+                    "No GPU visible to the process, check CUDA_VISIBLE_DEVICES or use a CPU build"
+                )
+            }
         }
     }
 }
@@ -329,7 +351,7 @@ pub enum SupportedFheCiphertexts {
     Scalar(Vec<u8>),
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::EnumIter)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::EnumIter, strum::EnumString)]
 #[repr(i8)]
 pub enum SupportedFheOperations {
     FheAdd = 0,
@@ -391,6 +413,34 @@ impl SupportedFheCiphertexts {
         }
     }
 
+    /// Size in bytes of the host-side serialized ciphertext, i.e. of
+    /// [`Self::serialize`]'s output, computed without allocating it. Scalars
+    /// are never serialized, so for them this is the length of their bytes
+    pub fn get_serialized_size(&self) -> usize {
+        match self {
+            SupportedFheCiphertexts::FheBool(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheUint4(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheUint8(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheUint16(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheUint32(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheUint64(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheUint128(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheUint160(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheUint256(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheBytes64(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheBytes128(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::FheBytes256(v) => safe_serialized_size(v),
+            SupportedFheCiphertexts::Scalar(v) => v.len(),
+        }
+    }
+
+    /// Host-side cost of the decrypt path, in bytes, to budget it separately
+    /// from FHE operations. The path serializes the whole ciphertext, so this
+    /// is its exact serialized size rather than an estimate
+    pub fn get_decrypt_cost(&self) -> u64 {
+        self.get_serialized_size() as u64
+    }
+
     pub fn to_ciphertext64(self) -> BaseRadixCiphertext<Ciphertext> {
         match self {
             SupportedFheCiphertexts::FheBool(v) => {
@@ -633,6 +683,37 @@ impl SupportedFheOperations {
         }
     }
 
+    /// Range of operand counts the operation accepts
+    pub fn required_arity(&self) -> Range<usize> {
+        match self.op_type() {
+            FheOperationType::Binary => 2..3,
+            FheOperationType::Unary => 1..2,
+            FheOperationType::Other => match self {
+                SupportedFheOperations::FheIfThenElse | SupportedFheOperations::FheRandBounded => {
+                    3..4
+                }
+                SupportedFheOperations::FheCast
+                | SupportedFheOperations::FheTrivialEncrypt
+                | SupportedFheOperations::FheRand => 2..3,
+                _ => 1..2,
+            },
+        }
+    }
+
+    pub fn check_arity(&self, operand_count: usize) -> Result<(), FhevmError> {
+        let arity = self.required_arity();
+        if arity.contains(&operand_count) {
+            Ok(())
+        } else {
+            Err(FhevmError::UnexpectedOperandCountForFheOperation {
+                fhe_operation: *self as i32,
+                fhe_operation_name: format!("{:?}", self),
+                expected_operands: arity.start,
+                got_operands: operand_count,
+            })
+        }
+    }
+
     pub fn is_comparison(&self) -> bool {
         matches!(
             self,
@@ -654,11 +735,16 @@ impl SupportedFheOperations {
         )
     }
 
+    /// Comparisons, including the ordered ones, not and the bitwise ops accept bools
     pub fn supports_bool_inputs(&self) -> bool {
         matches!(
             self,
             SupportedFheOperations::FheEq
                 | SupportedFheOperations::FheNe
+                | SupportedFheOperations::FheGe
+                | SupportedFheOperations::FheGt
+                | SupportedFheOperations::FheLe
+                | SupportedFheOperations::FheLt
                 | SupportedFheOperations::FheNot
                 | SupportedFheOperations::FheBitAnd
                 | SupportedFheOperations::FheBitOr
@@ -780,6 +866,12 @@ pub fn is_ebytes_type(inp: i16) -> bool {
     (9..=11).contains(&inp)
 }
 
+/// Whether the tag is the type number of a ciphertext variant, see
+/// [`SupportedFheCiphertexts::type_num`]
+pub fn is_valid_type_tag(tag: i16) -> bool {
+    (0..=11).contains(&tag)
+}
+
 #[derive(Copy, Clone, Debug)]
 pub enum AllowEvents {
     AllowedAccount = 0,
@@ -800,3 +892,175 @@ impl TryFrom<i16> for AllowEvents {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::FhevmKeys;
+    use crate::tfhe_ops::deserialize_fhe_ciphertext;
+    use crate::utils::SAFE_SER_DESER_LIMIT;
+    use tfhe::integer::bigint::StaticUnsignedBigInt;
+    use tfhe::prelude::FheEncrypt;
+
+    fn encrypt_all_types(client_key: &tfhe::ClientKey) -> Vec<SupportedFheCiphertexts> {
+        vec![
+            SupportedFheCiphertexts::FheBool(tfhe::FheBool::encrypt(true, client_key)),
+            SupportedFheCiphertexts::FheUint4(tfhe::FheUint4::encrypt(1u8, client_key)),
+            SupportedFheCiphertexts::FheUint8(tfhe::FheUint8::encrypt(1u8, client_key)),
+            SupportedFheCiphertexts::FheUint16(tfhe::FheUint16::encrypt(1u16, client_key)),
+            SupportedFheCiphertexts::FheUint32(tfhe::FheUint32::encrypt(1u32, client_key)),
+            SupportedFheCiphertexts::FheUint64(tfhe::FheUint64::encrypt(1u64, client_key)),
+            SupportedFheCiphertexts::FheUint128(tfhe::FheUint128::encrypt(1u128, client_key)),
+            SupportedFheCiphertexts::FheUint160(tfhe::FheUint160::encrypt(
+                U256::from(1u8),
+                client_key,
+            )),
+            SupportedFheCiphertexts::FheUint256(tfhe::FheUint256::encrypt(
+                U256::from(1u8),
+                client_key,
+            )),
+            SupportedFheCiphertexts::FheBytes64(tfhe::FheUint512::encrypt(
+                StaticUnsignedBigInt::<8>::from(1u8),
+                client_key,
+            )),
+            SupportedFheCiphertexts::FheBytes128(tfhe::FheUint1024::encrypt(
+                StaticUnsignedBigInt::<16>::from(1u8),
+                client_key,
+            )),
+            SupportedFheCiphertexts::FheBytes256(tfhe::FheUint2048::encrypt(
+                StaticUnsignedBigInt::<32>::from(1u8),
+                client_key,
+            )),
+        ]
+    }
+
+    #[test]
+    fn required_arity_by_operation() {
+        assert_eq!(SupportedFheOperations::FheAdd.required_arity(), 2..3);
+        assert_eq!(SupportedFheOperations::FheNot.required_arity(), 1..2);
+        assert_eq!(SupportedFheOperations::FheNeg.required_arity(), 1..2);
+        assert_eq!(SupportedFheOperations::FheIfThenElse.required_arity(), 3..4);
+        assert_eq!(SupportedFheOperations::FheRand.required_arity(), 2..3);
+        assert_eq!(
+            SupportedFheOperations::FheRandBounded.required_arity(),
+            3..4
+        );
+    }
+
+    #[test]
+    fn check_arity_rejects_wrong_operand_count() {
+        assert!(SupportedFheOperations::FheMul.check_arity(2).is_ok());
+        for count in [0, 1, 3] {
+            match SupportedFheOperations::FheMul.check_arity(count) {
+                Err(FhevmError::UnexpectedOperandCountForFheOperation {
+                    expected_operands,
+                    got_operands,
+                    ..
+                }) => {
+                    assert_eq!(expected_operands, 2);
+                    assert_eq!(got_operands, count);
+                }
+                _ => panic!("expected operand count error for {count} operands"),
+            }
+        }
+    }
+
+    #[test]
+    fn serialized_size_grows_with_width() {
+        let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+        let sizes = [
+            SupportedFheCiphertexts::FheBool(tfhe::FheBool::encrypt(true, &client_key)),
+            SupportedFheCiphertexts::FheUint8(tfhe::FheUint8::encrypt(1u8, &client_key)),
+            SupportedFheCiphertexts::FheUint64(tfhe::FheUint64::encrypt(1u64, &client_key)),
+            SupportedFheCiphertexts::FheUint256(tfhe::FheUint256::encrypt(
+                tfhe::integer::U256::from(1u8),
+                &client_key,
+            )),
+        ]
+        .iter()
+        .map(|ct| ct.get_serialized_size())
+        .collect::<Vec<_>>();
+
+        assert!(sizes[0] > 0);
+        assert!(
+            sizes.windows(2).all(|w| w[0] < w[1]),
+            "sizes not increasing: {sizes:?}"
+        );
+        assert_eq!(
+            SupportedFheCiphertexts::Scalar(vec![1, 2, 3]).get_serialized_size(),
+            3
+        );
+    }
+
+    #[test]
+    fn serialized_size_matches_serialize() {
+        let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+        for ct in encrypt_all_types(&client_key) {
+            // larger than serialize() accepts uncompressed
+            if ct.get_serialized_size() > SAFE_SER_DESER_LIMIT as usize {
+                assert_eq!(ct.type_num(), 11);
+                continue;
+            }
+            assert_eq!(
+                ct.get_serialized_size(),
+                ct.serialize().1.len(),
+                "{}",
+                ct.type_name()
+            );
+        }
+    }
+
+    #[test]
+    fn decrypt_cost_grows_with_width() {
+        let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+        let cts = encrypt_all_types(&client_key);
+        let costs = cts
+            .iter()
+            .map(|ct| ct.get_decrypt_cost())
+            .collect::<Vec<_>>();
+        for (ct, cost) in cts.iter().zip(&costs) {
+            assert_eq!(*cost, ct.get_serialized_size() as u64);
+        }
+        assert!(
+            costs.windows(2).all(|w| w[0] < w[1]),
+            "costs not increasing: {costs:?}"
+        );
+    }
+
+    #[test]
+    fn type_tags_round_trip() {
+        let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+        let cts = encrypt_all_types(&client_key);
+        assert_eq!(cts.len(), 12);
+        for (expected_tag, ct) in cts.iter().enumerate() {
+            let tag = ct.type_num();
+            assert_eq!(tag, expected_tag as i16);
+            assert!(is_valid_type_tag(tag));
+            // uncompressed FheBytes256 is above the safe serialization limit
+            if let SupportedFheCiphertexts::FheBytes256(_) = ct {
+                continue;
+            }
+            let (_, bytes) = ct.serialize();
+            let back = deserialize_fhe_ciphertext(tag, &bytes).unwrap();
+            assert_eq!(back.type_num(), tag);
+        }
+        assert!(!is_valid_type_tag(-1));
+        assert!(!is_valid_type_tag(12));
+        assert!(!is_valid_type_tag(
+            SupportedFheCiphertexts::Scalar(vec![]).type_num()
+        ));
+    }
+
+    #[test]
+    fn type_names_are_distinct() {
+        let client_key = tfhe::ClientKey::generate(FhevmKeys::new_config());
+        let mut cts = encrypt_all_types(&client_key);
+        cts.push(SupportedFheCiphertexts::Scalar(vec![1]));
+        let names = cts
+            .iter()
+            .map(|ct| ct.type_name())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(names.len(), cts.len());
+        assert!(names.iter().all(|n| !n.is_empty()));
+    }
+}
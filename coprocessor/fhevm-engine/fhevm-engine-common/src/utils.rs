@@ -14,6 +14,29 @@ pub fn safe_serialize<T: Serialize + Named + Versionize>(object: &T) -> Vec<u8>
     out
 }
 
+/// Length of the [`safe_serialize`] output, computed without allocating it.
+/// Not bounded by [`SAFE_SER_DESER_LIMIT`], so objects too large for
+/// [`safe_serialize`] report their actual size
+pub fn safe_serialized_size<T: Serialize + Named + Versionize>(object: &T) -> usize {
+    let mut counter = ByteCounter(0);
+    tfhe::safe_serialization::safe_serialize(object, &mut counter, u64::MAX)
+        .expect("safe serialize succeeds");
+    counter.0
+}
+
+struct ByteCounter(usize);
+
+impl std::io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 pub fn safe_deserialize<T: DeserializeOwned + Named + Unversionize>(
     input: &[u8],
 ) -> Result<T, FhevmError> {
@@ -88,6 +88,13 @@ pub struct Args {
 
     #[arg(long, default_value = "8080", help = "Health check port")]
     pub health_port: u16,
+
+    #[arg(
+        long,
+        default_value = "5",
+        help = "Health check dependency probe timeout, in seconds"
+    )]
+    pub health_probe_timeout: u64,
 }
 
 type RProvider = FillProvider<
@@ -470,6 +477,7 @@ pub async fn main(args: Args) {
         cancel_token,
         &args.database_url,
         &args.url,
+        Duration::from_secs(args.health_probe_timeout),
     );
     {
         let health_check_clone = health_check.clone();
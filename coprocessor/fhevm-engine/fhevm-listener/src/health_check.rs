@@ -1,5 +1,5 @@
 use std::net::SocketAddr;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use std::sync::Arc;
 
@@ -30,6 +30,13 @@ pub struct Health {
     pub database_connected: bool,
     /// Blockchain provider connection status
     pub blockchain_connected: bool,
+    /// Blockchain provider state, distinguishing transient from lasting
+    /// failures
+    pub blockchain_provider: ProviderState,
+    /// Number of consecutive failed blockchain provider probes
+    pub blockchain_failures: u32,
+    /// Seconds since the first of the consecutive failed probes
+    pub blockchain_failing_for: u64,
     pub message: String,
     pub last_tick: u64,
 }
@@ -39,11 +46,82 @@ const UNHEALTHY: &str = "unhealthy";
 const OK: u16 = StatusCode::OK.as_u16();
 const INTERNAL_SERVER_ERROR: u16 = StatusCode::INTERNAL_SERVER_ERROR.as_u16();
 
+/// Consecutive failed probes after which the provider is considered down.
+/// A single failed probe is often a websocket reconnection, three in a row
+/// are not
+pub const PROVIDER_DOWN_FAILURE_THRESHOLD: u32 = 3;
+/// Failure duration (in seconds) after which the provider is considered down,
+/// whatever the number of probes, so a rarely polled /healthz still reports
+/// it
+pub const PROVIDER_DOWN_DURATION_SECS: u64 = 60;
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderState {
+    Connected,
+    /// Failing, but not for long enough to be considered down
+    Reconnecting,
+    Down,
+}
+
+/// Tracks consecutive blockchain provider probe failures across health checks
+///
+/// Every /healthz request probes the provider and records its outcome, so
+/// concurrent callers each add to the count: three quick requests during a
+/// single outage are enough to report the provider down
+#[derive(Clone, Debug, Default)]
+pub struct ProviderFailures {
+    consecutive: u32,
+    since: Option<u64>,
+}
+
+impl ProviderFailures {
+    /// Records the outcome of a probe made at `now` (in seconds) and returns
+    /// the resulting provider state
+    pub fn record(&mut self, connected: bool, now: u64) -> ProviderState {
+        if connected {
+            *self = Self::default();
+            return ProviderState::Connected;
+        }
+        self.consecutive += 1;
+        let since = *self.since.get_or_insert(now);
+        if self.consecutive >= PROVIDER_DOWN_FAILURE_THRESHOLD
+            || now.saturating_sub(since) >= PROVIDER_DOWN_DURATION_SECS
+        {
+            ProviderState::Down
+        } else {
+            ProviderState::Reconnecting
+        }
+    }
+
+    pub fn consecutive(&self) -> u32 {
+        self.consecutive
+    }
+
+    /// Seconds elapsed at `now` since the first consecutive failure
+    pub fn failing_for(&self, now: u64) -> u64 {
+        self.since.map_or(0, |since| now.saturating_sub(since))
+    }
+}
+
 impl Health {
+    /// Health before the listener connected. The provider is reported as
+    /// reconnecting rather than down, since nothing was probed yet and the
+    /// listener is still connecting to it
     pub fn initial() -> Self {
         Self {
             database_connected: false,
             blockchain_connected: false,
+            blockchain_provider: ProviderState::Reconnecting,
+            blockchain_failures: 0,
+            blockchain_failing_for: 0,
             message: "Not connected".to_string(),
             status_code: OK,
             status: UNHEALTHY,
@@ -59,6 +137,7 @@ impl Health {
         self.healthy = true;
         self.database_connected = true;
         self.blockchain_connected = true;
+        self.blockchain_provider = ProviderState::Connected;
         self.tick();
     }
 
@@ -71,14 +150,14 @@ impl Health {
         self.healthy = true;
         self.database_connected = true;
         self.blockchain_connected = true;
+        self.blockchain_provider = ProviderState::Connected;
+        self.blockchain_failures = 0;
+        self.blockchain_failing_for = 0;
         self.message = "".to_string();
     }
 
     pub fn tick(&mut self) {
-        self.last_tick = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        self.last_tick = now_secs();
     }
 
     pub fn unhealthy(&mut self) {
@@ -92,11 +171,7 @@ impl Health {
             // not connected yet
             return;
         }
-        let timestamp_now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        let elapsed_time = timestamp_now - self.last_tick;
+        let elapsed_time = now_secs() - self.last_tick;
         if elapsed_time > 30 {
             self.message += &format!("Last tick is too old {elapsed_time}.\n");
             self.unhealthy();
@@ -123,6 +198,21 @@ impl Health {
         self.database_connected = true;
     }
 
+    /// Records a database probe that did not answer in time as a failed,
+    /// non-fatal one
+    pub fn database_probe_timed_out(&mut self) {
+        self.message += "Database connection timed out.\n";
+        self.database_connected = false;
+    }
+
+    /// Records a blockchain probe that did not answer in time as a failed,
+    /// non-fatal one. It still counts as a provider failure, so repeated
+    /// timeouts make the provider down
+    pub fn blockchain_probe_timed_out(&mut self) {
+        self.message += "Blockchain connection timed out.\n";
+        self.blockchain_connected = false;
+    }
+
     pub async fn check_blockchain_connected(&mut self, blockchain_url: &str) {
         let ws = WsConnect::new(blockchain_url);
         let provider = ProviderBuilder::new().connect_ws(ws).await;
@@ -134,19 +224,88 @@ impl Health {
         };
         self.blockchain_connected = true;
     }
+
+    /// Checks whose outcome changed since the `previous` health check
+    pub fn transitions_from(&self, previous: &Health) -> Vec<HealthTransition> {
+        let mut transitions = vec![];
+        let mut compare = |check, from: String, to: String| {
+            if from != to {
+                transitions.push(HealthTransition { check, from, to });
+            }
+        };
+        compare(
+            "healthy",
+            previous.healthy.to_string(),
+            self.healthy.to_string(),
+        );
+        compare(
+            "database_connected",
+            previous.database_connected.to_string(),
+            self.database_connected.to_string(),
+        );
+        compare(
+            "blockchain_provider",
+            format!("{:?}", previous.blockchain_provider),
+            format!("{:?}", self.blockchain_provider),
+        );
+        transitions
+    }
+
+    /// Updates the provider state from the failures tracked across probes.
+    /// A provider down is unhealthy
+    pub fn set_blockchain_provider(
+        &mut self,
+        state: ProviderState,
+        failures: &ProviderFailures,
+        now: u64,
+    ) {
+        self.blockchain_provider = state;
+        self.blockchain_failures = failures.consecutive();
+        self.blockchain_failing_for = failures.failing_for(now);
+        if state == ProviderState::Down {
+            self.message += "Blockchain provider is down.\n";
+            self.unhealthy();
+        }
+    }
+}
+
+/// A check whose outcome changed between two health checks
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HealthTransition {
+    pub check: &'static str,
+    pub from: String,
+    pub to: String,
 }
 
 #[derive(Clone)]
 pub struct HealthStateContent {
     pub status: Health,
+    blockchain_failures: ProviderFailures,
+    /// Last reported health, to only log transitions
+    last_reported: Health,
     database_url: String,
     blockchain_url: String,
+    probe_timeout: Duration,
 }
 
 impl HealthStateContent {
     pub fn tick(&mut self) {
         self.status.tick();
     }
+
+    /// Logs the checks that changed since the last reported health, then
+    /// remembers `health` as the last reported one
+    fn report(&mut self, health: &Health) {
+        for transition in health.transitions_from(&self.last_reported) {
+            info!(
+                check = transition.check,
+                from = %transition.from,
+                to = %transition.to,
+                "Health check transition"
+            );
+        }
+        self.last_reported = health.clone();
+    }
 }
 
 pub type HealthState = Arc<RwLock<HealthStateContent>>;
@@ -164,11 +323,15 @@ impl HealthCheck {
         cancel_token: CancellationToken,
         database_url: &str,
         blockchain_url: &str,
+        probe_timeout: Duration,
     ) -> Self {
         let health_state = HealthStateContent {
             status: Health::initial(),
+            blockchain_failures: ProviderFailures::default(),
+            last_reported: Health::initial(),
             database_url: database_url.to_owned(),
             blockchain_url: blockchain_url.to_owned(),
+            probe_timeout,
         };
         Self {
             health_state: Arc::new(RwLock::new(health_state)),
@@ -212,14 +375,39 @@ async fn health_handler(
 ) -> impl IntoResponse {
     let mut health_state = state_health.read().await.clone();
     health_state.status.check_last_tick();
-    health_state
+    // a hung dependency must not hang the endpoint
+    let probe_timeout = health_state.probe_timeout;
+    let database_probe = health_state
         .status
-        .check_database_connected(&health_state.database_url)
-        .await;
-    health_state
+        .check_database_connected(&health_state.database_url);
+    if tokio::time::timeout(probe_timeout, database_probe)
+        .await
+        .is_err()
+    {
+        health_state.status.database_probe_timed_out();
+    }
+    let blockchain_probe = health_state
         .status
-        .check_blockchain_connected(&health_state.blockchain_url)
-        .await;
+        .check_blockchain_connected(&health_state.blockchain_url);
+    if tokio::time::timeout(probe_timeout, blockchain_probe)
+        .await
+        .is_err()
+    {
+        health_state.status.blockchain_probe_timed_out();
+    }
+    {
+        let now = now_secs();
+        let mut shared_state = state_health.write().await;
+        let failures = &mut shared_state.blockchain_failures;
+        let provider_state =
+            failures.record(health_state.status.blockchain_connected, now);
+        health_state.status.set_blockchain_provider(
+            provider_state,
+            failures,
+            now,
+        );
+        shared_state.report(&health_state.status);
+    }
     let health = health_state.status;
     let result = (
         StatusCode::from_u16(health.status_code)
@@ -245,3 +433,195 @@ async fn liveness_handler(
         })),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tracing::field::{Field, Visit};
+    use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+    /// Collects the `check` field of every info event
+    #[derive(Clone, Default)]
+    struct LoggedChecks(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for LoggedChecks {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "check" {
+                self.0.lock().unwrap().push(value.to_owned());
+            }
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) {}
+    }
+
+    impl<S: tracing::Subscriber> Layer<S> for LoggedChecks {
+        fn on_event(&self, event: &tracing::Event<'_>, _: Context<'_, S>) {
+            if *event.metadata().level() == tracing::Level::INFO {
+                event.record(&mut self.clone());
+            }
+        }
+    }
+
+    #[test]
+    fn provider_goes_down_after_failure_threshold() {
+        let mut failures = ProviderFailures::default();
+        assert_eq!(failures.record(true, 100), ProviderState::Connected);
+        for i in 1..PROVIDER_DOWN_FAILURE_THRESHOLD {
+            assert_eq!(
+                failures.record(false, 100 + i as u64),
+                ProviderState::Reconnecting
+            );
+            assert_eq!(failures.consecutive(), i);
+        }
+        assert_eq!(failures.record(false, 110), ProviderState::Down);
+        assert_eq!(failures.failing_for(110), 9);
+        assert_eq!(failures.record(true, 111), ProviderState::Connected);
+        assert_eq!(failures.consecutive(), 0);
+        assert_eq!(failures.failing_for(111), 0);
+    }
+
+    #[test]
+    fn provider_goes_down_after_failure_duration() {
+        let mut failures = ProviderFailures::default();
+        assert_eq!(failures.record(false, 0), ProviderState::Reconnecting);
+        assert_eq!(
+            failures.record(false, PROVIDER_DOWN_DURATION_SECS),
+            ProviderState::Down
+        );
+        assert_eq!(failures.consecutive(), 2);
+    }
+
+    #[tokio::test]
+    async fn hung_probes_time_out() {
+        // accepts connections but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut conns = vec![];
+            while let Ok((conn, _)) = listener.accept().await {
+                conns.push(conn);
+            }
+        });
+
+        let health_check = HealthCheck::new(
+            0,
+            CancellationToken::new(),
+            &format!("postgres://postgres@{addr}/coprocessor"),
+            &format!("ws://{addr}"),
+            Duration::from_millis(200),
+        );
+        health_check.connected().await;
+        for failures in 1..=PROVIDER_DOWN_FAILURE_THRESHOLD {
+            let response = tokio::time::timeout(
+                Duration::from_secs(5),
+                health_handler(State(health_check.health_state.clone())),
+            )
+            .await
+            .expect("health check must not hang")
+            .into_response();
+
+            let down = failures == PROVIDER_DOWN_FAILURE_THRESHOLD;
+            let expected_status = if down {
+                StatusCode::INTERNAL_SERVER_ERROR
+            } else {
+                StatusCode::OK
+            };
+            assert_eq!(response.status(), expected_status);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let json: serde_json::Value =
+                serde_json::from_slice(&body).unwrap();
+            assert_eq!(json["details"]["healthy"], !down);
+            assert_eq!(json["details"]["database_connected"], false);
+            assert_eq!(json["details"]["blockchain_connected"], false);
+            assert_eq!(json["details"]["blockchain_failures"], failures);
+            assert_eq!(
+                json["details"]["blockchain_provider"],
+                if down { "down" } else { "reconnecting" }
+            );
+        }
+    }
+
+    #[test]
+    fn health_reports_provider_state() {
+        let mut failures = ProviderFailures::default();
+        let mut health = Health::initial();
+        let state = failures.record(false, 10);
+        health.set_blockchain_provider(state, &failures, 15);
+        assert_eq!(health.blockchain_provider, ProviderState::Reconnecting);
+        assert_eq!(health.blockchain_failures, 1);
+        assert_eq!(health.blockchain_failing_for, 5);
+        let json = serde_json::to_value(&health).unwrap();
+        assert_eq!(json["blockchain_provider"], "reconnecting");
+    }
+
+    #[test]
+    fn only_changed_checks_are_transitions() {
+        let mut previous = Health::initial();
+        previous.connected();
+        let mut current = previous.clone();
+        current.message = "Blockchain connection failed.\n".to_string();
+        assert!(current.transitions_from(&previous).is_empty());
+
+        let mut failures = ProviderFailures::default();
+        for now in 0..PROVIDER_DOWN_FAILURE_THRESHOLD {
+            let state = failures.record(false, now as u64);
+            current.set_blockchain_provider(state, &failures, now as u64);
+        }
+        assert_eq!(
+            current.transitions_from(&previous),
+            vec![
+                HealthTransition {
+                    check: "healthy",
+                    from: "true".to_string(),
+                    to: "false".to_string(),
+                },
+                HealthTransition {
+                    check: "blockchain_provider",
+                    from: "Connected".to_string(),
+                    to: "Down".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn transitions_are_logged_once() {
+        let logged = LoggedChecks::default();
+        let subscriber = tracing_subscriber::registry().with(logged.clone());
+        let logged_checks = || logged.0.lock().unwrap().clone();
+
+        let health_check = HealthCheck::new(
+            0,
+            CancellationToken::new(),
+            "postgres://postgres@127.0.0.1/coprocessor",
+            "ws://127.0.0.1",
+            Duration::from_secs(1),
+        );
+        let mut state = health_check.health_state.try_write().unwrap();
+        let mut health = Health::initial();
+        tracing::subscriber::with_default(subscriber, || {
+            health.connected();
+            state.report(&health);
+            let connected =
+                ["healthy", "database_connected", "blockchain_provider"];
+            assert_eq!(logged_checks(), connected);
+            state.report(&health);
+            assert_eq!(logged_checks(), connected);
+
+            let mut failures = ProviderFailures::default();
+            for now in 0..PROVIDER_DOWN_FAILURE_THRESHOLD {
+                let provider = failures.record(false, now as u64);
+                health.set_blockchain_provider(provider, &failures, now as u64);
+            }
+            state.report(&health);
+            state.report(&health);
+            assert_eq!(
+                logged_checks(),
+                [&connected[..], &["healthy", "blockchain_provider"]].concat()
+            );
+        });
+    }
+}
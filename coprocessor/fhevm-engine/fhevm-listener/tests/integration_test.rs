@@ -162,6 +162,7 @@ async fn test_listener_restart() -> Result<(), anyhow::Error> {
         catchup_margin: 5,
         log_level: Level::INFO,
         health_port: 8080,
+        health_probe_timeout: 5,
     };
 
     // Start listener in background task
@@ -291,6 +292,7 @@ async fn test_health() -> Result<(), anyhow::Error> {
         catchup_margin: 5,
         log_level: Level::INFO,
         health_port: 8081,
+        health_probe_timeout: 5,
     };
 
     const LIVENESS_URL: &str = "http://0.0.0.0:8081/liveness";
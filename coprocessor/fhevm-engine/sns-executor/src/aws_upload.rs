@@ -11,7 +11,6 @@ use futures::future::join_all;
 use sha3::{Digest, Keccak256};
 
 use opentelemetry::global::BoxedSpan;
-use sqlx::postgres::PgPoolOptions;
 use sqlx::{PgPool, Pool, Postgres, Transaction};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -34,6 +33,7 @@ const DEFAULT_BATCH_SIZE: usize = 10;
 /// Process the S3 uploads
 pub(crate) async fn process_s3_uploads(
     conf: &Config,
+    pool: PgPool,
     mut jobs: mpsc::Receiver<UploadJob>,
     jobs_tx: mpsc::Sender<UploadJob>,
     token: CancellationToken,
@@ -43,13 +43,7 @@ pub(crate) async fn process_s3_uploads(
     let (is_ready_res, _) = check_is_ready(&client, conf).await;
     is_ready.store(is_ready_res, Ordering::Release);
 
-    let pool = Arc::new(
-        PgPoolOptions::new()
-            .max_connections(conf.db.max_connections)
-            .acquire_timeout(conf.db.timeout)
-            .connect(&conf.db.url)
-            .await?,
-    );
+    let pool = Arc::new(pool);
 
     // Spawn the resubmits_loop as a helper task
     tokio::spawn({
@@ -2,8 +2,8 @@ use std::sync::{atomic::AtomicBool, Arc};
 
 use fhevm_engine_common::telemetry;
 use sns_executor::{
-    compute_128bit_ct, create_s3_client, process_s3_uploads, Config, DBConfig, HealthCheckConfig,
-    S3Config, S3RetryPolicy, UploadJob,
+    compute_128bit_ct, create_s3_client, create_upload_pool, process_s3_uploads, Config, DBConfig,
+    HealthCheckConfig, S3Config, S3RetryPolicy, UploadJob,
 };
 
 use tokio::{signal::unix, spawn, sync::mpsc};
@@ -91,9 +91,21 @@ async fn main() {
     let (client, is_ready) = create_s3_client(&conf).await;
     let is_ready = Arc::new(AtomicBool::new(is_ready));
     let s3 = client.clone();
+    let upload_pool = match create_upload_pool(&conf).await {
+        Ok(pool) => pool,
+        Err(err) => {
+            error!(
+                "Failed to connect the upload-worker database pool: {:?}",
+                err
+            );
+            return;
+        }
+    };
+    let pool = upload_pool.clone();
 
     spawn(async move {
-        if let Err(err) = process_s3_uploads(&conf, uploads_rx, tx, token, s3, is_ready).await {
+        if let Err(err) = process_s3_uploads(&conf, pool, uploads_rx, tx, token, s3, is_ready).await
+        {
             error!("Failed to run the upload-worker : {:?}", err);
         }
     });
@@ -102,7 +114,7 @@ async fn main() {
 
     let conf = config.clone();
     let token = parent.child_token();
-    if let Err(err) = compute_128bit_ct(conf, uploads_tx, token, client).await {
+    if let Err(err) = compute_128bit_ct(conf, upload_pool, uploads_tx, token, client).await {
         error!("SnS worker failed: {:?}", err);
     }
 }
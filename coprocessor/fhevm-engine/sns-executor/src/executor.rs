@@ -6,7 +6,9 @@ use crate::KeySet;
 use crate::UploadJob;
 use crate::{Config, DBConfig, ExecutionError};
 use aws_sdk_s3::Client;
-use fhevm_engine_common::healthz_server::{HealthCheckService, HealthStatus, Version};
+use fhevm_engine_common::healthz_server::{
+    HealthCheckConfigSnapshot, HealthCheckService, HealthStatus, Version,
+};
 use fhevm_engine_common::telemetry;
 use fhevm_engine_common::types::{get_ct_type, SupportedFheCiphertexts};
 use fhevm_engine_common::utils::compact_hex;
@@ -28,6 +30,7 @@ const S3_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
 
 pub struct SwitchNSquashService {
     pool: PgPool,
+    upload_pool: PgPool,
     conf: Config,
     // Timestamp of the last moment the service was active
     last_active_at: Arc<RwLock<SystemTime>>,
@@ -39,6 +42,9 @@ impl HealthCheckService for SwitchNSquashService {
     async fn health_check(&self) -> HealthStatus {
         let mut status = HealthStatus::default();
         status.set_db_connected(&self.pool).await;
+        status
+            .set_db_pool_connected("database:uploader", &self.upload_pool)
+            .await;
 
         let mut is_s3_ready: bool = false;
         let mut is_s3_connected: bool = false;
@@ -86,11 +92,19 @@ impl HealthCheckService for SwitchNSquashService {
             build: "unknown",
         }
     }
+
+    fn get_config(&self) -> HealthCheckConfigSnapshot {
+        HealthCheckConfigSnapshot {
+            liveness_threshold_secs: Some(self.conf.health_checks.liveness_threshold.as_secs()),
+            tracked_workers: 0,
+        }
+    }
 }
 
 impl SwitchNSquashService {
     pub async fn create(
         conf: Config,
+        upload_pool: PgPool,
         tx: Sender<UploadJob>,
         token: CancellationToken,
         s3_client: Arc<Client>,
@@ -108,6 +122,7 @@ impl SwitchNSquashService {
 
         Ok(SwitchNSquashService {
             pool,
+            upload_pool,
             conf,
             last_active_at: Arc::new(RwLock::new(SystemTime::now())),
             token,
@@ -17,7 +17,7 @@ use fhevm_engine_common::{
     healthz_server::HttpServer, telemetry::OtelTracer, types::FhevmError, utils::compact_hex,
 };
 use serde::{Deserialize, Serialize};
-use sqlx::{Postgres, Transaction};
+use sqlx::{postgres::PgPoolOptions, PgPool, Postgres, Transaction};
 use thiserror::Error;
 use tokio::{
     sync::mpsc::{self, Sender},
@@ -249,6 +249,7 @@ impl UploadJob {
 /// Runs the SnS worker loop
 pub async fn compute_128bit_ct(
     conf: Config,
+    upload_pool: PgPool,
     tx: Sender<UploadJob>,
     token: CancellationToken,
     client: Arc<Client>,
@@ -256,8 +257,9 @@ pub async fn compute_128bit_ct(
     info!(target: "sns", "Worker started with {}", conf);
     let port = conf.health_checks.port;
 
-    let service =
-        Arc::new(SwitchNSquashService::create(conf, tx, token.child_token(), client).await?);
+    let service = Arc::new(
+        SwitchNSquashService::create(conf, upload_pool, tx, token.child_token(), client).await?,
+    );
 
     let http_server = HttpServer::new(service.clone(), port, token.child_token());
     let _http_handle = task::spawn(async move {
@@ -279,6 +281,7 @@ pub async fn compute_128bit_ct(
 /// Runs the uploader loop
 pub async fn process_s3_uploads(
     conf: &Config,
+    pool: PgPool,
     rx: mpsc::Receiver<UploadJob>,
     tx: Sender<UploadJob>,
     token: CancellationToken,
@@ -287,12 +290,23 @@ pub async fn process_s3_uploads(
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     info!(target: "sns", "Uploader started with {:?}", conf.s3);
 
-    aws_upload::process_s3_uploads(conf, rx, tx, token, client, is_ready).await?;
+    aws_upload::process_s3_uploads(conf, pool, rx, tx, token, client, is_ready).await?;
 
     info!(target: "sns", "Uploader stopped");
     Ok(())
 }
 
+/// Connects the database pool of the uploader. The worker health check
+/// reports it next to its own pool
+pub async fn create_upload_pool(conf: &Config) -> Result<PgPool, ExecutionError> {
+    let pool = PgPoolOptions::new()
+        .max_connections(conf.db.max_connections)
+        .acquire_timeout(conf.db.timeout)
+        .connect(&conf.db.url)
+        .await?;
+    Ok(pool)
+}
+
 /// Configure and create the S3 client.
 ///
 /// Logs errors if the connection fails or if any buckets are missing.
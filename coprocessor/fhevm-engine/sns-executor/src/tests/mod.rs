@@ -82,21 +82,11 @@ async fn test_decryptable(
     anyhow::Result::<()>::Ok(())
 }
 
-async fn setup() -> anyhow::Result<(
-    sqlx::PgPool,
-    Option<ClientKey>,
-    tokio::sync::mpsc::Receiver<UploadJob>,
-    DBInstance,
-)> {
-    tracing_subscriber::fmt().json().with_level(true).init();
-    let test_instance = test_harness::instance::setup_test_db()
-        .await
-        .expect("valid db instance");
-
-    let conf = Config {
+fn test_config(db_url: &str) -> Config {
+    Config {
         tenant_api_key: TENANT_API_KEY.to_string(),
         db: DBConfig {
-            url: test_instance.db_url().to_owned(),
+            url: db_url.to_owned(),
             listen_channels: vec![LISTEN_CHANNEL.to_string()],
             notify_channel: "fhevm".to_string(),
             batch_limit: 10,
@@ -112,7 +102,21 @@ async fn setup() -> anyhow::Result<(
             liveness_threshold: Duration::from_secs(10),
             port: 8080,
         },
-    };
+    }
+}
+
+async fn setup() -> anyhow::Result<(
+    sqlx::PgPool,
+    Option<ClientKey>,
+    tokio::sync::mpsc::Receiver<UploadJob>,
+    DBInstance,
+)> {
+    tracing_subscriber::fmt().json().with_level(true).init();
+    let test_instance = test_harness::instance::setup_test_db()
+        .await
+        .expect("valid db instance");
+
+    let conf = test_config(test_instance.db_url());
 
     let pool = sqlx::postgres::PgPoolOptions::new()
         .max_connections(conf.db.max_connections)
@@ -126,8 +130,9 @@ async fn setup() -> anyhow::Result<(
     let (client_key, _) = fetch_keys(&pool, &TENANT_API_KEY.to_owned()).await?;
     let (client, _) = create_s3_client(&conf).await;
 
+    let upload_pool = pool.clone();
     tokio::spawn(async move {
-        crate::compute_128bit_ct(conf, upload_tx, token, client)
+        crate::compute_128bit_ct(conf, upload_pool, upload_tx, token, client)
             .await
             .expect("valid worker");
         Ok(())
@@ -139,6 +144,36 @@ async fn setup() -> anyhow::Result<(
     Ok((pool, client_key, upload_rx, test_instance))
 }
 
+#[tokio::test]
+async fn test_config_reflects_liveness_threshold() {
+    use fhevm_engine_common::healthz_server::HealthCheckService;
+
+    let test_instance = test_harness::instance::setup_test_db()
+        .await
+        .expect("valid db instance");
+    let mut conf = test_config(test_instance.db_url());
+    conf.health_checks.liveness_threshold = Duration::from_secs(45);
+    let upload_pool = crate::create_upload_pool(&conf)
+        .await
+        .expect("valid db pool");
+    let (client, _) = create_s3_client(&conf).await;
+    let (upload_tx, _upload_rx) = mpsc::channel::<UploadJob>(10);
+
+    let service = crate::executor::SwitchNSquashService::create(
+        conf,
+        upload_pool,
+        upload_tx,
+        test_instance.parent_token.child_token(),
+        client,
+    )
+    .await
+    .expect("valid service");
+
+    let config = service.get_config();
+    assert_eq!(config.liveness_threshold_secs, Some(45));
+    assert_eq!(config.tracked_workers, 0);
+}
+
 #[derive(Serialize, Deserialize)]
 struct TestFile {
     pub handle: [u8; 32],
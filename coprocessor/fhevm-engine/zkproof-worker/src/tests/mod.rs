@@ -28,3 +28,74 @@ async fn test_verify_proof() {
     // Check if it's invalid
     assert!(!utils::is_valid(&pool, request_id_invalid).await.unwrap());
 }
+
+#[test]
+fn test_stale_worker_fails_workers_check() {
+    use std::time::{Duration, SystemTime};
+
+    let now = SystemTime::now();
+    let stale = now - Duration::from_secs(120);
+    assert!(crate::verifier::all_recent(&[now, now], 70));
+    assert!(!crate::verifier::all_recent(&[now, stale, now], 70));
+}
+
+#[tokio::test]
+async fn test_health_reports_oldest_worker_activity() {
+    use fhevm_engine_common::healthz_server::HealthCheckService;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let instance = test_harness::instance::setup_test_db()
+        .await
+        .expect("valid db instance");
+    let now = SystemTime::now();
+    let stale = now - Duration::from_secs(120);
+    let service = utils::service_with_workers(instance.db_url(), &[now, stale]).await;
+
+    let status = service.health_check().await;
+    assert_eq!(
+        status.last_ok_unix("workers"),
+        Some(stale.duration_since(UNIX_EPOCH).unwrap().as_secs())
+    );
+}
+
+#[tokio::test]
+async fn test_stale_worker_fails_health_check() {
+    use fhevm_engine_common::healthz_server::HealthCheckService;
+    use std::time::{Duration, SystemTime};
+
+    let instance = test_harness::instance::setup_test_db()
+        .await
+        .expect("valid db instance");
+    let now = SystemTime::now();
+    let stale = now - Duration::from_secs(120);
+
+    let service = utils::service_with_workers(instance.db_url(), &[now, now]).await;
+    let status = service.health_check().await;
+    assert_eq!(status.check("workers"), Some(true));
+    assert!(status.is_healthy());
+
+    let service = utils::service_with_workers(instance.db_url(), &[now, stale]).await;
+    assert!(service.is_alive().await);
+    let status = service.health_check().await;
+    assert_eq!(status.check("database"), Some(true));
+    assert_eq!(status.check("workers"), Some(false));
+    assert!(!status.is_healthy());
+    assert!(status.error_details().contains("not active"));
+}
+
+#[tokio::test]
+async fn test_config_reflects_polling_interval_and_workers() {
+    use fhevm_engine_common::healthz_server::HealthCheckService;
+    use std::time::SystemTime;
+
+    let instance = test_harness::instance::setup_test_db()
+        .await
+        .expect("valid db instance");
+    let now = SystemTime::now();
+    let service = utils::service_with_workers(instance.db_url(), &[now, now, now]).await;
+
+    let config = service.get_config();
+    // pg_polling_interval of the test service plus 10 seconds
+    assert_eq!(config.liveness_threshold_secs, Some(70));
+    assert_eq!(config.tracked_workers, 3);
+}
@@ -4,6 +4,7 @@ use std::time::{Duration, SystemTime};
 use test_harness::instance::DBInstance;
 use tokio::sync::RwLock;
 use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
 
 use crate::auxiliary::ZkData;
 
@@ -32,10 +33,12 @@ pub async fn setup() -> anyhow::Result<(sqlx::PgPool, DBInstance)> {
         .await
         .unwrap();
 
-    let last_active_at = Arc::new(RwLock::new(SystemTime::now()));
+    let workers_active_at = (0..conf.worker_thread_count)
+        .map(|_| Arc::new(RwLock::new(SystemTime::now())))
+        .collect::<Vec<_>>();
     let db_pool = pool.clone();
     tokio::spawn(async move {
-        crate::verifier::execute_verify_proofs_loop(db_pool, conf.clone(), last_active_at.clone())
+        crate::verifier::execute_verify_proofs_loop(db_pool, conf.clone(), workers_active_at)
             .await
             .unwrap();
     });
@@ -45,6 +48,31 @@ pub async fn setup() -> anyhow::Result<(sqlx::PgPool, DBInstance)> {
     Ok((pool, test_instance))
 }
 
+/// Creates a service whose workers were last active at `active_at`, without
+/// starting them
+pub(crate) async fn service_with_workers(
+    db_url: &str,
+    active_at: &[SystemTime],
+) -> crate::verifier::ZkProofService {
+    let pool = sqlx::postgres::PgPoolOptions::new()
+        .max_connections(1)
+        .connect(db_url)
+        .await
+        .expect("valid db pool");
+    let conf = crate::Config {
+        database_url: db_url.to_owned(),
+        pg_polling_interval: 60,
+        worker_thread_count: active_at.len() as u32,
+        ..Default::default()
+    };
+    let workers_active_at = active_at
+        .iter()
+        .map(|t| Arc::new(RwLock::new(*t)))
+        .collect();
+
+    crate::verifier::ZkProofService::new(pool, conf, CancellationToken::new(), workers_active_at)
+}
+
 pub(crate) async fn is_valid(pool: &sqlx::PgPool, zk_proof_id: i64) -> Result<bool, sqlx::Error> {
     let result = sqlx::query!(
         "SELECT verified FROM verify_proofs WHERE zk_proof_id = $1",
@@ -25,7 +25,9 @@ use std::sync::Arc;
 use std::time::SystemTime;
 use tfhe::set_server_key;
 
-use fhevm_engine_common::healthz_server::{HealthCheckService, HealthStatus, Version};
+use fhevm_engine_common::healthz_server::{
+    HealthCheckConfigSnapshot, HealthCheckService, HealthStatus, Version,
+};
 use tokio::time::interval;
 use tokio::{select, time::Duration};
 use tokio_util::sync::CancellationToken;
@@ -46,25 +48,43 @@ pub struct ZkProofService {
     conf: Config,
     _cancel_token: CancellationToken,
 
-    // Timestamp of the last moment the service was active
-    last_active_at: Arc<RwLock<SystemTime>>,
+    // Timestamp of the last moment each worker was active
+    workers_active_at: Vec<Arc<RwLock<SystemTime>>>,
 }
 impl HealthCheckService for ZkProofService {
     async fn health_check(&self) -> HealthStatus {
         let mut status = HealthStatus::default();
         status.set_db_connected(&self.pool).await;
+
+        // Unlike is_alive, every worker must have been active recently, so a
+        // single stuck worker is reported. Workers refresh their activity
+        // before each proof and at least every pg_polling_interval when idle,
+        // and verifying one proof takes seconds, not a polling interval
+        let workers_active_at = self.workers_active_at().await;
+        let workers_alive = all_recent(&workers_active_at, self.liveness_threshold());
+        if !workers_alive {
+            status.add_error_details("Some ZK-proof workers are not active".to_owned());
+        }
+        // all workers were last known active when the oldest one was
+        if let Some(oldest) = workers_active_at.into_iter().min() {
+            if oldest > SystemTime::UNIX_EPOCH {
+                status.set_last_ok_at("workers", oldest);
+            }
+        }
+        status.set_custom_check("workers", workers_alive);
+
         status
     }
 
     async fn is_alive(&self) -> bool {
-        let last_active_at = *self.last_active_at.read().await;
-        let threshold = self.conf.pg_polling_interval + 10;
-
-        (SystemTime::now()
-            .duration_since(last_active_at)
-            .map(|d| d.as_secs())
-            .unwrap_or(u64::MAX) as u32)
-            < threshold
+        let last_active_at = self
+            .workers_active_at()
+            .await
+            .into_iter()
+            .max()
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+
+        is_recent(last_active_at, self.liveness_threshold())
     }
 
     fn get_version(&self) -> Version {
@@ -75,6 +95,13 @@ impl HealthCheckService for ZkProofService {
             build: "unknown",
         }
     }
+
+    fn get_config(&self) -> HealthCheckConfigSnapshot {
+        HealthCheckConfigSnapshot {
+            liveness_threshold_secs: Some(self.liveness_threshold() as u64),
+            tracked_workers: self.workers_active_at.len(),
+        }
+    }
 }
 
 impl ZkProofService {
@@ -92,11 +119,26 @@ impl ZkProofService {
             .await
             .expect("valid db pool");
 
+        let workers_active_at = (0..conf.worker_thread_count)
+            .map(|_| Arc::new(RwLock::new(SystemTime::UNIX_EPOCH)))
+            .collect();
+
+        Self::new(pool, conf, cancel_token, workers_active_at)
+    }
+
+    /// Creates the service over an existing pool, with one worker per entry
+    /// of `workers_active_at`
+    pub(crate) fn new(
+        pool: PgPool,
+        conf: Config,
+        cancel_token: CancellationToken,
+        workers_active_at: Vec<Arc<RwLock<SystemTime>>>,
+    ) -> ZkProofService {
         ZkProofService {
             pool,
             conf,
             _cancel_token: cancel_token,
-            last_active_at: Arc::new(RwLock::new(SystemTime::UNIX_EPOCH)),
+            workers_active_at,
         }
     }
 
@@ -104,17 +146,42 @@ impl ZkProofService {
         execute_verify_proofs_loop(
             self.pool.clone(),
             self.conf.clone(),
-            self.last_active_at.clone(),
+            self.workers_active_at.clone(),
         )
         .await
     }
+
+    async fn workers_active_at(&self) -> Vec<SystemTime> {
+        let mut res = Vec::with_capacity(self.workers_active_at.len());
+        for active_at in &self.workers_active_at {
+            res.push(*active_at.read().await);
+        }
+        res
+    }
+
+    fn liveness_threshold(&self) -> u32 {
+        self.conf.pg_polling_interval + 10
+    }
 }
+
+fn is_recent(active_at: SystemTime, threshold: u32) -> bool {
+    (SystemTime::now()
+        .duration_since(active_at)
+        .map(|d| d.as_secs())
+        .unwrap_or(u64::MAX) as u32)
+        < threshold
+}
+
+pub(crate) fn all_recent(active_at: &[SystemTime], threshold: u32) -> bool {
+    active_at.iter().all(|t| is_recent(*t, threshold))
+}
+
 /// Executes the main loop for handling verify_proofs requests inserted in the
-/// database
+/// database, with one worker per entry of `workers_active_at`
 pub async fn execute_verify_proofs_loop(
     pool: PgPool,
     conf: Config,
-    last_active_at: Arc<RwLock<SystemTime>>,
+    workers_active_at: Vec<Arc<RwLock<SystemTime>>>,
 ) -> Result<(), ExecutionError> {
     info!("Starting with config {:?}", conf);
 
@@ -125,14 +192,13 @@ pub async fn execute_verify_proofs_loop(
 
     let t = telemetry::tracer("init_workers");
     let mut s = t.child_span("start_workers");
-    telemetry::attribute(&mut s, "count", conf.worker_thread_count.to_string());
+    telemetry::attribute(&mut s, "count", workers_active_at.len().to_string());
     let mut task_set = JoinSet::new();
 
-    for _ in 0..conf.worker_thread_count {
+    for last_active_at in workers_active_at {
         let conf = conf.clone();
         let tenant_key_cache = tenant_key_cache.clone();
         let pool = pool.clone();
-        let last_active_at = last_active_at.clone();
 
         // Spawn a ZK-proof worker
         // All workers compete for zk-proof tasks queued in the 'verify_proof' table.